@@ -0,0 +1,160 @@
+use std::time::Duration;
+use std::{env, process};
+
+use shared::answers::Verdict;
+use shared::output::{self, Format};
+use shared::runner::{self, Options, PartResult};
+use shared::{Error, Input, Solver};
+
+const USAGE: &str = "Usage: aoc run <day|all> [--part <1|2>] [--bench [--runs <N>]]\n           [--format <text|json|csv>] [input|-]";
+
+/// Every day that can be dispatched by the runner, in calendar order.
+const SOLVERS: &[&dyn Solver] = &[
+    &day_1::Day1,
+    &day_2::Day2,
+    &day_3::Day3,
+    &day_4::Day4,
+    &day_5::Day5,
+    &day_6::Day6,
+    &day_7::Day7,
+    &day_8::Day8,
+];
+
+fn main() {
+    match do_main() {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Returns whether every part produced its expected answer.
+fn do_main() -> shared::Result<bool> {
+    let mut args = env::args().skip(1);
+
+    match args.next().as_deref() {
+        Some("run") => {}
+        _ => return Err(Error::invalid(USAGE)),
+    }
+
+    let target = match args.next() {
+        Some(target) => target,
+        None => return Err(Error::invalid(USAGE)),
+    };
+
+    let options = Options::parse(args)?;
+
+    let solvers = if target == "all" {
+        if options.input.is_some() {
+            return Err(Error::invalid(
+                "An input file cannot be used with `run all`",
+            ));
+        }
+
+        SOLVERS.to_vec()
+    } else {
+        vec![find_solver(&target)?]
+    };
+
+    if options.bench && target == "all" {
+        return bench_all(&options);
+    }
+
+    if options.bench {
+        let input = Input::resolve(solvers[0].day(), options.input.as_deref());
+        let report = runner::bench_day(solvers[0], &input, &options.parts(), options.runs())?;
+        output::print_reports(&[report], options.format);
+
+        return Ok(true);
+    }
+
+    if target == "all" {
+        return run_all(&options);
+    }
+
+    let input = Input::resolve(solvers[0].day(), options.input.as_deref());
+    let results = runner::run_day(solvers[0], &input, &options.parts())?;
+    output::print_results(&results, options.format);
+
+    Ok(!results.iter().any(PartResult::failed))
+}
+
+/// Run every registered day against its default input and print a summary
+/// table, or every part's result when a machine-readable format is chosen.
+/// A day whose input cannot be read or parsed shows up as an error in every
+/// part, without stopping the other days.
+fn run_all(options: &Options) -> shared::Result<bool> {
+    let mut results = vec![];
+
+    for solver in SOLVERS {
+        let input = Input::default_for(solver.day());
+
+        match runner::run_day(*solver, &input, &options.parts()) {
+            Ok(day_results) => results.extend(day_results),
+            Err(e) => results.extend(options.parts().into_iter().map(|part| PartResult {
+                day: solver.day(),
+                part,
+                answer: Err(Error::invalid(e.to_string())),
+                verdict: Verdict::Unknown,
+                elapsed: Duration::ZERO,
+            })),
+        }
+    }
+
+    match options.format {
+        Format::Text => runner::print_summary(&results),
+        format => output::print_results(&results, format),
+    }
+
+    for result in &results {
+        match &result.answer {
+            Err(e) => eprintln!("Day {} part {}: {}", result.day, result.part, e),
+            Ok(answer) if result.failed() => eprintln!(
+                "Day {} part {}: unexpected answer {}",
+                result.day, result.part, answer
+            ),
+            Ok(_) => {}
+        }
+    }
+
+    Ok(!results.iter().any(PartResult::failed))
+}
+
+/// Benchmark every registered day against its default input. A day whose
+/// input cannot be read or solved is reported on stderr, without stopping
+/// the other days.
+fn bench_all(options: &Options) -> shared::Result<bool> {
+    let mut reports = vec![];
+    let mut all_ran = true;
+
+    for solver in SOLVERS {
+        let input = Input::default_for(solver.day());
+
+        match runner::bench_day(*solver, &input, &options.parts(), options.runs()) {
+            Ok(report) => reports.push(report),
+            Err(e) => {
+                eprintln!("Day {}: {}", solver.day(), e);
+                all_ran = false;
+            }
+        }
+    }
+
+    output::print_reports(&reports, options.format);
+
+    Ok(all_ran)
+}
+
+fn find_solver(day: &str) -> shared::Result<&'static dyn Solver> {
+    let number: u8 = day
+        .parse()
+        .map_err(|_| Error::invalid(format!("Invalid day `{}`", day)))?;
+
+    SOLVERS
+        .iter()
+        .copied()
+        .find(|solver| solver.day() == number)
+        .ok_or_else(|| Error::invalid(format!("Day {} has no solver", number)))
+}
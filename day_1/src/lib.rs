@@ -0,0 +1,60 @@
+use shared::{parse, Answer, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<u32>;
+
+    fn parse(input: &str) -> shared::Result<Self::Parsed> {
+        parse_elf_counts(input)
+    }
+
+    fn part1(elf_counts: &Self::Parsed) -> shared::Result<Answer> {
+        Ok(most_calories(elf_counts)?.into())
+    }
+
+    fn part2(elf_counts: &Self::Parsed) -> shared::Result<Answer> {
+        Ok(top_3_calories(elf_counts.clone()).into())
+    }
+}
+
+/// Total the calories carried by each elf. Elves are separated by blank lines.
+fn parse_elf_counts(contents: &str) -> shared::Result<Vec<u32>> {
+    parse::blocks(contents)
+        .into_iter()
+        .map(|elf| {
+            elf.into_iter()
+                .map(|line| line.complete(|c| c.number::<u32>()))
+                .sum()
+        })
+        .collect()
+}
+
+// --------------------------------
+// Part 1
+// --------------------------------
+
+fn most_calories(elf_counts: &[u32]) -> shared::Result<u32> {
+    match elf_counts.iter().max() {
+        Some(max) => Ok(*max),
+        None => Err(shared::Error::unsolvable(
+            "Unable to get calorie counts for elves",
+        )),
+    }
+}
+
+// --------------------------------
+// Part 2
+// --------------------------------
+
+fn top_3_calories(mut elf_counts: Vec<u32>) -> u32 {
+    elf_counts.sort_by(|val0, val1| val1.cmp(val0));
+    elf_counts.iter().take(3).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    shared::example_tests!(super::Day1, example);
+}
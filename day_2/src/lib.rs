@@ -0,0 +1,210 @@
+use shared::{Answer, Solution};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<GuideEntry>;
+
+    fn parse(input: &str) -> shared::Result<Self::Parsed> {
+        parse_guide(input)
+    }
+
+    fn part1(guide: &Self::Parsed) -> shared::Result<Answer> {
+        Ok(tally_scores(&rounds_part1(guide)).into())
+    }
+
+    fn part2(guide: &Self::Parsed) -> shared::Result<Answer> {
+        Ok(tally_scores(&rounds_part2(guide)).into())
+    }
+}
+
+// The result of a single round. The first item is the elf play,
+// and the second is the player's response.
+type Round = (Play, Play);
+
+// A single line of the strategy guide. The first item is the elf play,
+// and the second is the still-encrypted response column.
+type GuideEntry = (Play, Response);
+
+/// Parse the contents of the file into a vector of guide entries.
+/// For elf:
+/// A -> Rock
+/// B -> Paper
+/// C -> Scissors
+///
+/// The response column (X, Y or Z) is interpreted differently by each part.
+fn parse_guide(contents: &str) -> shared::Result<Vec<GuideEntry>> {
+    contents
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            let invalid =
+                |column: usize, message: &str| shared::Error::parse(idx + 1, column, line, message);
+
+            // Split a string at spaces
+            let (elf_play, response) = line
+                .split_once(' ')
+                .ok_or_else(|| invalid(line.len() + 1, "expected two columns"))?;
+
+            let elf_play = match elf_play {
+                "A" => Play::Rock,
+                "B" => Play::Paper,
+                "C" => Play::Scissors,
+                _ => return Err(invalid(1, "expected A, B or C")),
+            };
+
+            let response = match response {
+                "X" => Response::X,
+                "Y" => Response::Y,
+                "Z" => Response::Z,
+                _ => {
+                    return Err(invalid(
+                        line.len() - response.len() + 1,
+                        "expected X, Y or Z",
+                    ))
+                }
+            };
+
+            Ok((elf_play, response))
+        })
+        .collect()
+}
+
+/// Decrypt the guide into rounds.
+/// For player:
+/// X -> Rock
+/// Y -> Paper
+/// Z -> Scissors
+fn rounds_part1(guide: &[GuideEntry]) -> Vec<Round> {
+    guide
+        .iter()
+        .map(|(elf_play, response)| {
+            let user_play = match response {
+                Response::X => Play::Rock,
+                Response::Y => Play::Paper,
+                Response::Z => Play::Scissors,
+            };
+
+            (*elf_play, user_play)
+        })
+        .collect()
+}
+
+/// Decrypt the guide into rounds.
+/// For player:
+/// X -> Lose
+/// Y -> Draw
+/// Z -> Win
+///
+/// The player's response is based on the elf's play.
+fn rounds_part2(guide: &[GuideEntry]) -> Vec<Round> {
+    guide
+        .iter()
+        .map(|(elf_play, response)| {
+            let user_play = match response {
+                Response::X => match elf_play {
+                    Play::Rock => Play::Scissors,
+                    Play::Paper => Play::Rock,
+                    Play::Scissors => Play::Paper,
+                },
+                Response::Y => *elf_play,
+                Response::Z => match elf_play {
+                    Play::Rock => Play::Paper,
+                    Play::Paper => Play::Scissors,
+                    Play::Scissors => Play::Rock,
+                },
+            };
+
+            (*elf_play, user_play)
+        })
+        .collect()
+}
+
+fn get_round_result(round: &Round) -> RoundResult {
+    let (elf, player) = round;
+    match elf {
+        Play::Rock => match player {
+            Play::Rock => RoundResult::Draw,
+            Play::Paper => RoundResult::Win,
+            Play::Scissors => RoundResult::Loss,
+        },
+        Play::Paper => match player {
+            Play::Rock => RoundResult::Loss,
+            Play::Paper => RoundResult::Draw,
+            Play::Scissors => RoundResult::Win,
+        },
+        Play::Scissors => match player {
+            Play::Rock => RoundResult::Win,
+            Play::Paper => RoundResult::Loss,
+            Play::Scissors => RoundResult::Draw,
+        },
+    }
+}
+
+fn tally_scores(rounds: &[Round]) -> u32 {
+    let mut total_score = 0;
+
+    for round in rounds {
+        let choice_score = match round.1 {
+            Play::Rock => 1,
+            Play::Paper => 2,
+            Play::Scissors => 3,
+        };
+
+        total_score += choice_score;
+
+        let result_score = match get_round_result(round) {
+            RoundResult::Win => 6,
+            RoundResult::Draw => 3,
+            RoundResult::Loss => 0,
+        };
+
+        total_score += result_score;
+    }
+
+    total_score
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Play {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+/// The second column of the strategy guide, before it is decrypted.
+#[derive(Debug, Clone, Copy)]
+pub enum Response {
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Debug)]
+enum RoundResult {
+    Win,
+    Loss,
+    Draw,
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::Solution;
+
+    use super::Day2;
+
+    shared::example_tests!(super::Day2, example);
+
+    #[test]
+    fn it_points_at_a_malformed_line() {
+        // An unknown response.
+        assert_eq!(
+            Some((2, 3)),
+            Day2::parse("A Y\nB Q\nC Z")
+                .err()
+                .and_then(|e| e.position())
+        );
+    }
+}
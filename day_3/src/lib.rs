@@ -0,0 +1,133 @@
+use shared::{Answer, Solution};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> shared::Result<Self::Parsed> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| parse_sack(idx + 1, line))
+            .collect()
+    }
+
+    // --------------------------------
+    // Part 1
+    // --------------------------------
+
+    fn part1(sacks: &Self::Parsed) -> shared::Result<Answer> {
+        let common: shared::Result<Vec<_>> = sacks.iter().map(|sack| find_common(sack)).collect();
+        let points: u32 = common?.iter().map(points_for_type).sum();
+
+        Ok(points.into())
+    }
+
+    // --------------------------------
+    // Part 2
+    // --------------------------------
+
+    fn part2(sacks: &Self::Parsed) -> shared::Result<Answer> {
+        let points: u32 = find_groups(sacks)?.iter().map(points_for_type).sum();
+
+        Ok(points.into())
+    }
+}
+
+/// A sack is a line of item types (`a-z` and `A-Z`), split evenly
+/// between its two compartments.
+fn parse_sack(line_number: usize, line: &str) -> shared::Result<String> {
+    if let Some(idx) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+        return Err(shared::Error::parse(
+            line_number,
+            idx + 1,
+            line,
+            "expected an item type (a-z or A-Z)",
+        ));
+    }
+
+    if !line.len().is_multiple_of(2) {
+        return Err(shared::Error::parse(
+            line_number,
+            line.len(),
+            line,
+            "expected an even number of items",
+        ));
+    }
+
+    Ok(line.to_string())
+}
+
+fn find_common(line: &str) -> shared::Result<u8> {
+    let length = line.len();
+    let left = &line[0..length / 2];
+    let right = &line[length / 2..length];
+
+    for c in left.as_bytes() {
+        if right.as_bytes().contains(c) {
+            return Ok(*c);
+        }
+    }
+
+    Err(shared::Error::unsolvable("Unable to find common character"))
+}
+
+fn find_groups(content: &[String]) -> shared::Result<Vec<u8>> {
+    let mut groups: Vec<u8> = vec![];
+
+    if !content.len().is_multiple_of(3) {
+        return Err(shared::Error::unsolvable(
+            "The number of sacks is not a multiple of 3",
+        ));
+    }
+
+    for chunk in content.chunks(3) {
+        let first = chunk[0].as_bytes();
+        let second = chunk[1].as_bytes();
+        let third = chunk[2].as_bytes();
+
+        for c in first {
+            if second.contains(c) && third.contains(c) {
+                groups.push(*c);
+                break;
+            }
+        }
+    }
+
+    Ok(groups)
+}
+
+fn points_for_type(item_type: &u8) -> u32 {
+    if item_type >= &b'a' {
+        let point = item_type - b'a' + 1;
+
+        point as u32
+    } else {
+        let point = item_type - b'A' + 27;
+
+        point as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::Solution;
+
+    use super::Day3;
+
+    shared::example_tests!(super::Day3, example);
+
+    #[test]
+    fn it_points_at_a_malformed_line() {
+        // An item that is not a letter.
+        assert_eq!(
+            Some((2, 17)),
+            Day3::parse("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGL1rsFMfFZSrLrFZsSL\n")
+                .err()
+                .and_then(|e| e.position())
+        );
+    }
+}
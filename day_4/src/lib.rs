@@ -0,0 +1,104 @@
+use std::ops::RangeInclusive;
+
+use shared::parse::{self, Cursor};
+use shared::{Answer, Solution};
+
+pub struct Day4;
+
+type Pair = (RangeInclusive<u32>, RangeInclusive<u32>);
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<Pair>;
+
+    fn parse(input: &str) -> shared::Result<Self::Parsed> {
+        parse::lines(input, parse_pair)
+    }
+
+    // --------------------------------
+    // Part 1
+    // --------------------------------
+
+    fn part1(pairs: &Self::Parsed) -> shared::Result<Answer> {
+        let has_contained = pairs
+            .iter()
+            .filter(|(left, right)| {
+                if left.start() >= right.start() && left.end() <= right.end() {
+                    return true;
+                }
+
+                if right.start() >= left.start() && right.end() <= left.end() {
+                    return true;
+                }
+
+                false
+            })
+            .count();
+
+        Ok(has_contained.into())
+    }
+
+    // --------------------------------
+    // Part 2
+    // --------------------------------
+
+    fn part2(pairs: &Self::Parsed) -> shared::Result<Answer> {
+        let has_overlap = pairs
+            .iter()
+            .map(|(left, right)| {
+                let mut count = 0;
+
+                for i in left.clone() {
+                    if right.contains(&i) {
+                        count += 1;
+                    }
+                }
+
+                count
+            })
+            .filter(|val| val > &0)
+            .count();
+
+        Ok(has_overlap.into())
+    }
+}
+
+/// Convert a line of text like this: `17-57,55-96`
+/// into a tuple of left and right inclusive Ranges: `(17..=57, 55..=96)`
+fn parse_pair(line: &mut Cursor) -> shared::Result<Pair> {
+    let left = parse_range(line)?;
+    line.literal(",")?;
+    let right = parse_range(line)?;
+
+    Ok((left, right))
+}
+
+/// Convert one half of a line, like `17-57`, into an inclusive Range.
+fn parse_range(line: &mut Cursor) -> shared::Result<RangeInclusive<u32>> {
+    let low = line.number()?;
+    line.literal("-")?;
+    let high = line.number()?;
+
+    Ok(low..=high)
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::Solution;
+
+    use super::Day4;
+
+    shared::example_tests!(super::Day4, example);
+
+    #[test]
+    fn it_points_at_a_malformed_line() {
+        // A section that is not a number.
+        assert_eq!(
+            Some((2, 3)),
+            Day4::parse("2-4,6-8\n5-x,1-2\n")
+                .err()
+                .and_then(|e| e.position())
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use shared::parse::{self, Cursor};
+use shared::{Answer, Solution};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Parsed = Procedure;
+
+    fn parse(input: &str) -> shared::Result<Self::Parsed> {
+        let mut blocks = parse::blocks(input).into_iter();
+
+        // The first block of the input has the stack layout
+        let mut stack_lines = blocks.next().unwrap_or_default();
+
+        // Remove row listing stack numbers
+        stack_lines.pop();
+
+        if stack_lines.is_empty() {
+            return Err(shared::Error::parse(1, 1, "", "expected a stack layout"));
+        }
+
+        let stack_lines = stack_lines
+            .iter()
+            .map(line_to_stack_vecs)
+            .collect::<shared::Result<Vec<_>>>()?;
+
+        let stacks = stack_lines_to_stacks(stack_lines);
+        let directions = blocks
+            .flatten()
+            .map(|line| line.complete(|c| parse_direction(c, stacks.len())))
+            .collect::<shared::Result<Vec<Direction>>>()?;
+
+        Ok(Procedure { stacks, directions })
+    }
+
+    fn part1(procedure: &Self::Parsed) -> shared::Result<Answer> {
+        let mut stacks = procedure.stacks.clone();
+
+        for direction in &procedure.directions {
+            move_one_at_a_time(&mut stacks, direction)?;
+        }
+
+        Ok(top_crates(&stacks).into())
+    }
+
+    fn part2(procedure: &Self::Parsed) -> shared::Result<Answer> {
+        let mut stacks = procedure.stacks.clone();
+
+        for direction in &procedure.directions {
+            move_as_group(&mut stacks, direction)?;
+        }
+
+        Ok(top_crates(&stacks).into())
+    }
+}
+
+/// The starting stacks and the rearrangement steps to apply to them.
+pub struct Procedure {
+    stacks: Vec<Stack>,
+    directions: Vec<Direction>,
+}
+
+type Stack = Vec<char>;
+
+/// Takes a line like:
+/// `    [B] [C]`
+/// and converts it into:
+/// `None, Some('B'), Some('C')`
+fn line_to_stack_vecs(line: &Cursor) -> shared::Result<Vec<Option<char>>> {
+    line.columns(4)
+        .into_iter()
+        .map(|column| {
+            let column = column.trimmed();
+
+            if column.is_empty() {
+                return Ok(None);
+            }
+
+            column.complete(|c| {
+                c.literal("[")?;
+                let crate_type = c.next_char()?;
+                c.literal("]")?;
+
+                Ok(Some(crate_type))
+            })
+        })
+        .collect()
+}
+
+/// Takes a Vec of parsed lines and builds a Vec of Stacks.
+fn stack_lines_to_stacks(mut stack_lines: Vec<Vec<Option<char>>>) -> Vec<Stack> {
+    let stack_count: usize = stack_lines.iter().map(Vec::len).max().unwrap_or(0);
+    let mut stacks: Vec<Stack> = Vec::new();
+
+    // Set up the initial stacks.
+    for _i in 0..stack_count {
+        stacks.push(Vec::new());
+    }
+
+    stack_lines.reverse();
+
+    for line in stack_lines {
+        for (i, crate_type) in line.into_iter().enumerate() {
+            if let Some(ct) = crate_type {
+                stacks[i].push(ct);
+            }
+        }
+    }
+
+    stacks
+}
+
+#[derive(Debug)]
+pub struct Direction {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+/// Takes a line like `move 1 from 2 to 1` and checks that both stacks exist.
+fn parse_direction(line: &mut Cursor, stack_count: usize) -> shared::Result<Direction> {
+    line.literal("move ")?;
+    let count = line.number()?;
+    line.literal(" from ")?;
+    let from = parse_stack(line, stack_count)?;
+    line.literal(" to ")?;
+    let to = parse_stack(line, stack_count)?;
+
+    Ok(Direction { count, from, to })
+}
+
+fn parse_stack(line: &mut Cursor, stack_count: usize) -> shared::Result<usize> {
+    let start = *line;
+    let stack: usize = line.number()?;
+
+    if !(1..=stack_count).contains(&stack) {
+        return Err(start.error(format!("expected a stack between 1 and {}", stack_count)));
+    }
+
+    Ok(stack)
+}
+
+fn move_one_at_a_time(stacks: &mut [Stack], direction: &Direction) -> shared::Result<()> {
+    for _i in 0..direction.count {
+        let crate_type = stacks[direction.from - 1]
+            .pop()
+            .ok_or_else(|| not_enough_crates(direction))?;
+        stacks[direction.to - 1].push(crate_type);
+    }
+
+    Ok(())
+}
+
+fn move_as_group(stacks: &mut [Stack], direction: &Direction) -> shared::Result<()> {
+    let split_idx = stacks[direction.from - 1]
+        .len()
+        .checked_sub(direction.count)
+        .ok_or_else(|| not_enough_crates(direction))?;
+    let mut removed = stacks[direction.from - 1].split_off(split_idx);
+
+    stacks[direction.to - 1].append(&mut removed);
+
+    Ok(())
+}
+
+fn not_enough_crates(direction: &Direction) -> shared::Error {
+    shared::Error::unsolvable(format!(
+        "Unable to move {} crates from stack {}",
+        direction.count, direction.from
+    ))
+}
+
+/// The crate on top of each stack, as a single string.
+fn top_crates(stacks: &[Stack]) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::Solution;
+
+    use super::Day5;
+
+    shared::example_tests!(super::Day5, example);
+
+    #[test]
+    fn it_points_at_a_malformed_line() {
+        // A move from a stack that does not exist.
+        let input = concat!(
+            "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n",
+            "\n",
+            "move 1 from 2 to 1\n",
+            "move 3 from 9 to 3\n",
+        );
+
+        assert_eq!(
+            Some((7, 13)),
+            Day5::parse(input).err().and_then(|e| e.position())
+        );
+    }
+}
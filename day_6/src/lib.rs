@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+
+use shared::{Answer, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Parsed = String;
+
+    fn parse(input: &str) -> shared::Result<Self::Parsed> {
+        Ok(input.trim_end().to_string())
+    }
+
+    // ------------------------------------------------
+    // Part 1
+    // ------------------------------------------------
+
+    fn part1(datastream: &Self::Parsed) -> shared::Result<Answer> {
+        let start_of_packet_marker = Marker::find_in_string(datastream, 4);
+
+        if !start_of_packet_marker.is_valid() {
+            return Err(shared::Error::unsolvable(
+                "No marker found in the datastream",
+            ));
+        }
+
+        Ok(start_of_packet_marker.position.into())
+    }
+
+    // ------------------------------------------------
+    // Part 2
+    // ------------------------------------------------
+
+    fn part2(datastream: &Self::Parsed) -> shared::Result<Answer> {
+        let start_of_message_marker = Marker::find_in_string(datastream, 14);
+
+        if !start_of_message_marker.is_valid() {
+            return Err(shared::Error::unsolvable(
+                "No marker found in the datastream",
+            ));
+        }
+
+        Ok(start_of_message_marker.position.into())
+    }
+}
+
+struct Marker {
+    packet_size: usize,
+    position: usize,
+    value: String,
+}
+
+impl Marker {
+    pub fn new(packet_size: usize) -> Self {
+        Self {
+            packet_size,
+            position: 0,
+            value: String::new(),
+        }
+    }
+
+    pub fn find_in_string(data: &str, size: usize) -> Self {
+        let mut marker = Marker::new(size);
+
+        for chr in data.chars() {
+            marker.append(chr);
+
+            if marker.is_valid() {
+                break;
+            }
+        }
+
+        marker
+    }
+
+    pub fn append(&mut self, ch: char) -> &Self {
+        if self.value.len() >= self.packet_size {
+            self.value.remove(0);
+        }
+
+        self.value.push(ch);
+        self.position += 1;
+
+        self
+    }
+
+    pub fn is_valid(&self) -> bool {
+        if self.position < self.packet_size {
+            return false;
+        }
+
+        let unique_chs: HashSet<char> = self.value.chars().collect();
+
+        unique_chs.len() == self.packet_size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    shared::example_tests!(
+        super::Day6,
+        example_1,
+        example_2,
+        example_3,
+        example_4,
+        example_5
+    );
+}
@@ -0,0 +1,250 @@
+//! A tree of files and directories kept in a single arena.
+//!
+//! Nodes refer to each other by [`NodeId`], an index into the arena, so
+//! there are no reference cycles between parents and children. Every
+//! directory caches the total size of everything below it, which is kept up
+//! to date as files are added.
+
+use std::collections::BTreeMap;
+
+/// A file or directory in a [`FileSystem`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+/// Whether a node is a file or a directory, with the directory's entries by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeKind {
+    File,
+    Dir(BTreeMap<String, NodeId>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeData {
+    name: String,
+    parent: Option<NodeId>,
+    kind: NodeKind,
+    /// The size of a file, or the total size of every file below a directory.
+    size: usize,
+}
+
+impl NodeData {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> &NodeKind {
+        &self.kind
+    }
+
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, NodeKind::Dir(_))
+    }
+}
+
+/// Files and directories below a single root directory `/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSystem {
+    nodes: Vec<NodeData>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileSystem {
+    /// The root directory, which every file system has.
+    pub const ROOT: NodeId = NodeId(0);
+
+    /// A file system with nothing but an empty root directory.
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![NodeData {
+                name: "/".to_string(),
+                parent: None,
+                kind: NodeKind::Dir(BTreeMap::new()),
+                size: 0,
+            }],
+        }
+    }
+
+    pub fn node(&self, id: NodeId) -> &NodeData {
+        &self.nodes[id.0]
+    }
+
+    /// The directory a node is in, or `None` for the root.
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).parent
+    }
+
+    /// The size of a file, or of everything below a directory.
+    pub fn size(&self, id: NodeId) -> usize {
+        self.node(id).size
+    }
+
+    /// The entry called `name` in a directory.
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        match &self.node(dir).kind {
+            NodeKind::Dir(children) => children.get(name).copied(),
+            NodeKind::File => None,
+        }
+    }
+
+    /// The entries of a directory, by name. Files have none.
+    pub fn children(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let children = match &self.node(id).kind {
+            NodeKind::Dir(children) => Some(children.values().copied()),
+            NodeKind::File => None,
+        };
+
+        children.into_iter().flatten()
+    }
+
+    /// Every directory, the root included.
+    pub fn dirs(&self) -> impl Iterator<Item = NodeId> + '_ {
+        (0..self.nodes.len())
+            .map(NodeId)
+            .filter(|id| self.node(*id).is_dir())
+    }
+
+    /// The full path of a node, like `/a/e/i`.
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = vec![];
+        let mut node = Some(id);
+
+        while let Some(id) = node.filter(|id| *id != Self::ROOT) {
+            names.push(self.node(id).name.as_str());
+            node = self.parent(id);
+        }
+
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// The directory called `name` in `dir`, which is created if it does not exist yet.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `dir` is a file or `name` is a file in `dir`.
+    pub fn mkdir(&mut self, dir: NodeId, name: &str) -> shared::Result<NodeId> {
+        match self.child_or_insert(dir, name, NodeKind::Dir(BTreeMap::new()))? {
+            id if self.node(id).is_dir() => Ok(id),
+            id => Err(shared::Error::invalid(format!(
+                "`{}` is a file, not a directory",
+                self.path(id)
+            ))),
+        }
+    }
+
+    /// Add a file of `size` called `name` to `dir`, updating the size of every
+    /// directory above it. Adding the same file again only changes its size.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `dir` is a file or `name` is a directory in `dir`.
+    pub fn add_file(&mut self, dir: NodeId, name: &str, size: usize) -> shared::Result<NodeId> {
+        let id = self.child_or_insert(dir, name, NodeKind::File)?;
+
+        if self.node(id).is_dir() {
+            return Err(shared::Error::invalid(format!(
+                "`{}` is a directory, not a file",
+                self.path(id)
+            )));
+        }
+
+        let old = std::mem::replace(&mut self.nodes[id.0].size, size);
+        let mut ancestor = self.parent(id);
+
+        while let Some(dir) = ancestor {
+            let node = &mut self.nodes[dir.0];
+            node.size = node.size - old + size;
+            ancestor = node.parent;
+        }
+
+        Ok(id)
+    }
+
+    fn child_or_insert(
+        &mut self,
+        dir: NodeId,
+        name: &str,
+        kind: NodeKind,
+    ) -> shared::Result<NodeId> {
+        let next = NodeId(self.nodes.len());
+
+        let NodeKind::Dir(children) = &mut self.nodes[dir.0].kind else {
+            return Err(shared::Error::invalid(format!(
+                "`{}` is a file, not a directory",
+                self.path(dir)
+            )));
+        };
+
+        let id = *children.entry(name.to_string()).or_insert(next);
+
+        if id == next {
+            self.nodes.push(NodeData {
+                name: name.to_string(),
+                parent: Some(dir),
+                kind,
+                size: 0,
+            });
+        }
+
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FileSystem;
+
+    #[test]
+    fn it_caches_directory_sizes() {
+        let mut fs = FileSystem::new();
+        let a = fs.mkdir(FileSystem::ROOT, "a").unwrap();
+        let e = fs.mkdir(a, "e").unwrap();
+
+        fs.add_file(e, "i", 584).unwrap();
+        fs.add_file(a, "f", 29116).unwrap();
+        fs.add_file(FileSystem::ROOT, "b.txt", 14848514).unwrap();
+
+        assert_eq!(584, fs.size(e));
+        assert_eq!(29700, fs.size(a));
+        assert_eq!(14878214, fs.size(FileSystem::ROOT));
+
+        // Listing a file again replaces its size rather than adding to it.
+        fs.add_file(e, "i", 100).unwrap();
+        assert_eq!(100, fs.size(e));
+        assert_eq!(14877730, fs.size(FileSystem::ROOT));
+    }
+
+    #[test]
+    fn it_finds_parents_children_and_paths() {
+        let mut fs = FileSystem::new();
+        let a = fs.mkdir(FileSystem::ROOT, "a").unwrap();
+        let e = fs.mkdir(a, "e").unwrap();
+        let i = fs.add_file(e, "i", 584).unwrap();
+
+        assert_eq!(a, fs.mkdir(FileSystem::ROOT, "a").unwrap());
+        assert_eq!(Some(a), fs.parent(e));
+        assert_eq!(None, fs.parent(FileSystem::ROOT));
+        assert_eq!(Some(i), fs.child(e, "i"));
+        assert_eq!(vec![e], fs.children(a).collect::<Vec<_>>());
+        assert_eq!(0, fs.children(i).count());
+        assert_eq!("/a/e/i", fs.path(i));
+        assert_eq!("/", fs.path(FileSystem::ROOT));
+        assert_eq!(3, fs.dirs().count());
+    }
+
+    #[test]
+    fn it_keeps_files_and_directories_apart() {
+        let mut fs = FileSystem::new();
+        let file = fs.add_file(FileSystem::ROOT, "b.txt", 10).unwrap();
+        fs.mkdir(FileSystem::ROOT, "d").unwrap();
+
+        assert!(fs.mkdir(FileSystem::ROOT, "b.txt").is_err());
+        assert!(fs.add_file(FileSystem::ROOT, "d", 10).is_err());
+        assert!(fs.add_file(file, "c.txt", 10).is_err());
+    }
+}
@@ -0,0 +1,254 @@
+use shared::parse::{self, Cursor};
+use shared::{Answer, Solution};
+
+pub mod filesystem;
+
+use filesystem::FileSystem;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Parsed = FileSystem;
+
+    fn parse(input: &str) -> shared::Result<Self::Parsed> {
+        let commands = text_to_commands(input)?;
+
+        explore(&commands)
+    }
+
+    fn part1(fs: &Self::Parsed) -> shared::Result<Answer> {
+        let result: usize = fs
+            .dirs()
+            .map(|dir| fs.size(dir))
+            .filter(|size| *size <= 100000)
+            .sum();
+
+        Ok(result.into())
+    }
+
+    fn part2(fs: &Self::Parsed) -> shared::Result<Answer> {
+        let mut all_dir_sizes: Vec<usize> = fs.dirs().map(|dir| fs.size(dir)).collect();
+        all_dir_sizes.sort();
+
+        const FILE_SYSTEM_SIZE: usize = 70_000_000;
+        const NEEDED_SIZE: usize = 30_000_000;
+
+        let used_size = fs.size(FileSystem::ROOT);
+        let free_size = FILE_SYSTEM_SIZE.checked_sub(used_size).ok_or_else(|| {
+            shared::Error::unsolvable(format!(
+                "The files take up {} bytes, more than the whole disk",
+                used_size
+            ))
+        })?;
+
+        all_dir_sizes
+            .into_iter()
+            .find(|dir_size| free_size + dir_size > NEEDED_SIZE)
+            .map(Answer::from)
+            .ok_or_else(|| shared::Error::unsolvable("No directory frees up enough space"))
+    }
+}
+
+/// Replay the commands from the root directory, building up the file system they explore.
+/// A listing that conflicts with an earlier one is reported at the name it conflicts on.
+fn explore(commands: &[Command]) -> shared::Result<FileSystem> {
+    let mut fs = FileSystem::new();
+    let mut dir = FileSystem::ROOT;
+
+    for command in commands {
+        match command {
+            Command::Cd(cd) => {
+                dir = match cd.destination.rest() {
+                    "/" => FileSystem::ROOT,
+                    // Like a real shell, moving up from the root stays at the root.
+                    ".." => fs.parent(dir).unwrap_or(FileSystem::ROOT),
+                    name => fs
+                        .mkdir(dir, name)
+                        .map_err(|e| located(&cd.destination, e))?,
+                }
+            }
+
+            Command::Ls(ls) => {
+                for directory in &ls.directories {
+                    fs.mkdir(dir, directory.rest())
+                        .map_err(|e| located(directory, e))?;
+                }
+
+                for (file_name, file_size) in &ls.files {
+                    fs.add_file(dir, file_name.rest(), *file_size)
+                        .map_err(|e| located(file_name, e))?;
+                }
+            }
+        }
+    }
+
+    Ok(fs)
+}
+
+/// Point a file system error at the name in the input that caused it.
+fn located(name: &Cursor, error: shared::Error) -> shared::Error {
+    match error {
+        shared::Error::Invalid(message) => name.error(message),
+        e => e,
+    }
+}
+
+// ------------------------------------------------
+// Commands
+// ------------------------------------------------
+
+fn text_to_commands(content: &str) -> shared::Result<Vec<Command<'_>>> {
+    let mut commands: Vec<Command> = vec![];
+    let mut buffer: Vec<Cursor> = vec![];
+
+    for line in parse::cursors(content) {
+        if line.rest().starts_with("$ ") && !buffer.is_empty() {
+            let command = parse_single_command(&buffer)?;
+            commands.push(command);
+
+            buffer.clear();
+        }
+
+        buffer.push(line);
+    }
+
+    if !buffer.is_empty() {
+        let command = parse_single_command(&buffer)?;
+        commands.push(command);
+    }
+
+    Ok(commands)
+}
+
+/// Parse a command line and the lines of output that follow it.
+fn parse_single_command<'a>(lines: &[Cursor<'a>]) -> shared::Result<Command<'a>> {
+    let mut first_line = lines[0];
+    first_line.literal("$ ")?;
+
+    let cmd = first_line.take_until(" ");
+
+    let cmd = match cmd.rest() {
+        "cd" => {
+            let destination = first_line.take_rest();
+
+            if destination.is_empty() {
+                return Err(destination.error("expected a directory"));
+            }
+
+            Command::Cd(CdCommand::new(destination))
+        }
+
+        "ls" => {
+            first_line.end()?;
+            let mut ls_cmd = LsCommand::new();
+
+            for mut line in lines[1..].iter().copied() {
+                let (size, name) = line.key_value(" ")?;
+
+                if name.is_empty() {
+                    return Err(name.error("expected a name"));
+                }
+
+                if size.rest() == "dir" {
+                    ls_cmd.add_dir(name);
+                } else {
+                    let size: usize = size.complete(|c| c.number())?;
+                    ls_cmd.add_file(name, size);
+                }
+            }
+
+            Command::Ls(ls_cmd)
+        }
+        _ => return Err(cmd.error("expected `cd` or `ls`")),
+    };
+
+    Ok(cmd)
+}
+
+/// A command with the names it mentions, kept as cursors so errors can point at them.
+#[derive(Debug)]
+enum Command<'a> {
+    Cd(CdCommand<'a>),
+    Ls(LsCommand<'a>),
+}
+
+#[derive(Debug)]
+struct CdCommand<'a> {
+    destination: Cursor<'a>,
+}
+
+impl<'a> CdCommand<'a> {
+    fn new(destination: Cursor<'a>) -> Self {
+        CdCommand { destination }
+    }
+}
+
+#[derive(Debug)]
+struct LsCommand<'a> {
+    directories: Vec<Cursor<'a>>,
+    /// Files in the order they are listed.
+    files: Vec<(Cursor<'a>, usize)>,
+}
+
+impl<'a> LsCommand<'a> {
+    pub fn new() -> Self {
+        LsCommand {
+            directories: vec![],
+            files: vec![],
+        }
+    }
+
+    pub fn add_dir(&mut self, dir: Cursor<'a>) -> &Self {
+        self.directories.push(dir);
+        self
+    }
+
+    pub fn add_file(&mut self, file: Cursor<'a>, size: usize) -> &Self {
+        self.files.push((file, size));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::Solution;
+
+    use super::Day7;
+
+    shared::example_tests!(super::Day7, example);
+
+    #[test]
+    fn it_points_at_a_malformed_line() {
+        // An unknown command.
+        assert_eq!(
+            Some((4, 3)),
+            Day7::parse("$ cd /\n$ ls\ndir a\n$ rm x\n")
+                .err()
+                .and_then(|e| e.position())
+        );
+    }
+
+    #[test]
+    fn it_points_at_a_listing_that_contradicts_an_earlier_one() {
+        let position = |input| Day7::parse(input).err()?.position();
+
+        // `a` is listed as a file, then entered as a directory.
+        assert_eq!(Some((3, 6)), position("$ ls\n10 a\n$ cd a\n"));
+        // `a` is listed as a directory, then as a file.
+        assert_eq!(Some((5, 4)), position("$ ls\ndir a\n$ cd /\n$ ls\n10 a\n"));
+        // `b` is listed as a file, then as a directory.
+        assert_eq!(Some((5, 5)), position("$ ls\n10 b\n$ cd /\n$ ls\ndir b\n"));
+    }
+
+    #[test]
+    fn it_cannot_free_space_on_an_overfull_disk() {
+        let fs = Day7::parse("$ cd /\n$ ls\n80000000 big").unwrap();
+
+        assert!(matches!(
+            Day7::part2(&fs),
+            Err(shared::Error::Unsolvable(_))
+        ));
+    }
+}
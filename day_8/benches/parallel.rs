@@ -0,0 +1,63 @@
+//! Compares the sequential and parallel answers on a large generated forest:
+//!
+//! ```sh
+//! cargo bench -p day_8 --features parallel -- [size] [runs]
+//! ```
+
+use std::env;
+use std::time::{Duration, Instant};
+
+use day_8::grid::Grid;
+use shared::bench::Stats;
+
+const DEFAULT_SIZE: usize = 2000;
+const DEFAULT_RUNS: usize = 5;
+
+fn main() {
+    let mut numbers = env::args().skip(1).filter_map(|arg| arg.parse().ok());
+    let size = numbers.next().unwrap_or(DEFAULT_SIZE);
+    let runs = numbers.next().unwrap_or(DEFAULT_RUNS);
+
+    println!("Generating a {0}x{0} forest", size);
+    let forest = Grid::random(size, size, 8);
+
+    let part1 = || forest.visible_count();
+    let part2 = || forest.best_scenic_spot().map_or(0, |(_, score)| score);
+    let par_part1 = || forest.par_visible_count();
+    let par_part2 = || forest.par_best_scenic_spot().map_or(0, |(_, score)| score);
+
+    assert_eq!(part1(), par_part1(), "visible trees differ");
+    assert_eq!(part2(), par_part2(), "best scenic scores differ");
+
+    println!(
+        "{:<17} | {:>10} | {:>10} | {:>10}",
+        "Phase", "Min", "Median", "Max"
+    );
+    println!("------------------+------------+------------+-----------");
+    report("part 1 sequential", time(runs, part1));
+    report("part 1 parallel", time(runs, par_part1));
+    report("part 2 sequential", time(runs, part2));
+    report("part 2 parallel", time(runs, par_part2));
+}
+
+fn time(runs: usize, f: impl Fn() -> usize) -> Vec<Duration> {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(f());
+            start.elapsed()
+        })
+        .collect()
+}
+
+fn report(phase: &str, timings: Vec<Duration>) {
+    if let Some(stats) = Stats::from_timings(&timings) {
+        println!(
+            "{:<17} | {:>10} | {:>10} | {:>10}",
+            phase,
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.max)
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! The ways tree heights can be written in the input.
+
+use shared::grid;
+use shared::parse::{self, Cursor};
+
+use crate::grid::Height;
+
+/// How the tree heights of a forest are written, one row of trees per line.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Decoder {
+    /// A single digit per tree, `0` to `9`, as in the puzzle.
+    #[default]
+    Digits,
+    /// A single lowercase letter per tree, `a` to `z` for 0 to 25.
+    Letters,
+    /// Whole numbers up to 65535, separated by whitespace or commas, for
+    /// real elevation data.
+    Numbers,
+}
+
+impl Decoder {
+    /// Parse a decoder name as given on the command line.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "digits" => Some(Decoder::Digits),
+            "letters" => Some(Decoder::Letters),
+            "numbers" => Some(Decoder::Numbers),
+            _ => None,
+        }
+    }
+
+    /// Decode the tree heights of a forest.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    ///   - The forest is empty
+    ///   - A tree height cannot be decoded
+    ///   - A row is not as wide as the first one
+    pub fn decode(self, input: &str) -> shared::Result<grid::Grid<Height>> {
+        match self {
+            Decoder::Digits => grid::Grid::from_chars(input, "a tree height (0-9)", |c| {
+                c.to_digit(10).map(|height| height as Height)
+            }),
+            Decoder::Letters => grid::Grid::from_chars(input, "a tree height (a-z)", |c| {
+                c.is_ascii_lowercase().then(|| (c as u8 - b'a') as Height)
+            }),
+            Decoder::Numbers => decode_numbers(input),
+        }
+    }
+}
+
+fn decode_numbers(input: &str) -> shared::Result<grid::Grid<Height>> {
+    let mut cells = vec![];
+    let mut width = None;
+    let mut height = 0;
+
+    for mut cursor in parse::cursors(input) {
+        let row = numbers(&mut cursor)?;
+        let width = *width.get_or_insert(row.len());
+
+        if row.len() != width {
+            return Err(shared::Error::parse(
+                cursor.line_number(),
+                1,
+                cursor.line(),
+                format!(
+                    "expected a row of {} tree heights, found {}",
+                    width,
+                    row.len()
+                ),
+            ));
+        }
+
+        cells.extend(row);
+        height += 1;
+    }
+
+    match width {
+        Some(width) if width > 0 => grid::Grid::from_cells(width, height, cells),
+        _ => Err(shared::Error::parse(
+            1,
+            1,
+            "",
+            "expected a row of tree heights",
+        )),
+    }
+}
+
+/// The heights on one line, separated by whitespace or by a single comma
+/// with optional whitespace around it. An empty field between two commas,
+/// or after a trailing comma, is an error rather than being skipped.
+fn numbers(cursor: &mut Cursor) -> shared::Result<Vec<Height>> {
+    let mut row = vec![];
+    cursor.take_while(char::is_whitespace);
+
+    while !cursor.is_empty() {
+        row.push(cursor.number()?);
+
+        let spaced = !cursor.take_while(char::is_whitespace).is_empty();
+
+        if cursor.eat(",") {
+            cursor.take_while(char::is_whitespace);
+
+            if cursor.is_empty() || cursor.rest().starts_with(',') {
+                return Err(cursor.error("expected a tree height after the comma"));
+            }
+        } else if !spaced && !cursor.is_empty() {
+            return Err(cursor.error("expected a comma or whitespace between tree heights"));
+        }
+    }
+
+    Ok(row)
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::grid::Point;
+
+    use super::Decoder;
+
+    #[test]
+    fn it_finds_decoders_by_name() {
+        assert_eq!(Some(Decoder::Digits), Decoder::from_name("digits"));
+        assert_eq!(Some(Decoder::Letters), Decoder::from_name("letters"));
+        assert_eq!(Some(Decoder::Numbers), Decoder::from_name("numbers"));
+        assert_eq!(None, Decoder::from_name("hex"));
+    }
+
+    #[test]
+    fn it_decodes_digits_and_letters() {
+        let digits = Decoder::Digits.decode("30\n25").unwrap();
+        let letters = Decoder::Letters.decode("az\nmb").unwrap();
+
+        assert_eq!(&[3, 0, 2, 5], digits.cells());
+        assert_eq!(&[0, 25, 12, 1], letters.cells());
+        assert!(Decoder::Letters.decode("aZ").is_err());
+    }
+
+    #[test]
+    fn it_decodes_separated_numbers() {
+        let trees = Decoder::Numbers
+            .decode("120, 4000 ,7\n 65535\t0,0\n")
+            .unwrap();
+
+        assert_eq!((3, 2), (trees.width(), trees.height()));
+        assert_eq!(65535, trees[Point::new(0, 1)]);
+
+        let position = |input| Decoder::Numbers.decode(input).err()?.position();
+
+        assert_eq!(Some((1, 4)), position("1, 65536"));
+        assert_eq!(Some((1, 2)), position("1x"));
+        assert_eq!(Some((1, 1)), position("\n"));
+        assert_eq!(Some((1, 3)), position("1,,2"));
+        assert_eq!(Some((1, 6)), position("1 ,  , 2"));
+        assert_eq!(Some((1, 3)), position("1,"));
+        assert_eq!(Some((1, 1)), position(",1"));
+    }
+
+    #[test]
+    fn it_reports_ragged_rows() {
+        let position = |decoder: Decoder, input| decoder.decode(input).err()?.position();
+
+        assert_eq!(Some((2, 3)), position(Decoder::Digits, "123\n45"));
+        assert_eq!(Some((2, 3)), position(Decoder::Letters, "abc\nde"));
+        assert_eq!(Some((2, 1)), position(Decoder::Numbers, "1 2 3\n4 5"));
+    }
+}
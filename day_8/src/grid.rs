@@ -1,167 +1,416 @@
+use std::cmp::Reverse;
+use std::collections::BTreeSet;
+use std::ops::{BitOr, BitOrAssign};
+
+use shared::grid::{self, Direction, Point};
+use shared::random::Rng;
+
+use crate::decode::Decoder;
+use crate::scenic::ScenicPolicy;
+use crate::sweep;
+
+/// The height of a tree. The puzzle only has heights from 0 to 9, but
+/// other [`Decoder`]s read taller trees.
+pub type Height = u16;
+
+/// The heights of the trees in the forest.
 pub struct Grid {
-    data: Vec<Vec<u8>>,
-    pub width: usize,
-    pub height: usize,
+    trees: grid::Grid<Height>,
 }
 
 impl Grid {
-    pub fn new(data: &str) -> Self {
-        let data: Vec<Vec<u8>> = data
-            .lines()
-            .map(|line| {
-                line.chars()
-                    .map(|c| c.to_digit(10).unwrap() as u8)
-                    .collect()
-            })
-            .collect();
+    /// Parse a grid of single digit tree heights, one row per line.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    ///   - The grid is empty
+    ///   - A tree height is not a digit
+    ///   - A row is not as wide as the first one
+    pub fn new(data: &str) -> shared::Result<Self> {
+        Self::parse(data, Decoder::Digits)
+    }
 
-        let width = data[0].len();
-        let height = data.len();
+    /// Parse a grid of tree heights written the way `decoder` reads them.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the heights cannot be decoded,
+    /// see [`Decoder::decode`].
+    pub fn parse(data: &str, decoder: Decoder) -> shared::Result<Self> {
+        let trees = decoder.decode(data)?;
 
-        Self {
-            data,
-            width,
-            height,
-        }
+        Ok(Self { trees })
     }
 
-    pub fn is_visible(&self, x: usize, y: usize) -> bool {
-        // Trees along the edges are visible.
-        if (x == 0 || x == self.width - 1) || (y == 0 || y == self.height) {
-            return true;
-        }
+    pub fn width(&self) -> usize {
+        self.trees.width()
+    }
 
-        let value = self.data[y][x];
+    pub fn height(&self) -> usize {
+        self.trees.height()
+    }
 
-        // Left
-        let mut visible_left = true;
-        for x_compare in 0..x {
-            let value_compare = self.data[y][x_compare];
+    /// A forest of random tree heights. The same seed always grows the same forest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is zero.
+    pub fn random(width: usize, height: usize, seed: u64) -> Self {
+        assert!(width > 0 && height > 0, "A forest needs at least one tree");
 
-            if value_compare >= value {
-                visible_left = false;
-                break;
-            }
-        }
+        let mut rng = Rng::new(seed);
+        let trees = grid::Grid::from_fn(width, height, |_| rng.below(10) as Height);
 
-        // Top
-        let mut visible_top = true;
-        for y_compare in 0..y {
-            let value_compare = self.data[y_compare][x];
+        Self { trees }
+    }
 
-            if value_compare >= value {
-                visible_top = false;
-                break;
-            }
-        }
+    /// Plant, grow or cut down the tree at `(x, y)`, returning its old height.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `(x, y)` is outside of the grid.
+    pub fn set_height(&mut self, x: usize, y: usize, height: Height) -> shared::Result<Height> {
+        let (width, depth) = (self.width(), self.height());
+        let tree = self.trees.get_mut(Point::new(x, y)).ok_or_else(|| {
+            shared::Error::invalid(format!(
+                "({}, {}) is outside of the {}x{} forest",
+                x, y, width, depth
+            ))
+        })?;
+
+        Ok(std::mem::replace(tree, height))
+    }
+
+    /// The height of every tree.
+    pub fn trees(&self) -> &grid::Grid<Height> {
+        &self.trees
+    }
 
-        // Right
-        let mut visible_right = true;
-        for x_compare in (x + 1)..self.width {
-            let value_compare = self.data[y][x_compare];
+    /// The edges every tree can be seen from, found by sweeping each row
+    /// and column from both ends while tracking the tallest tree so far.
+    /// Takes O(width × height) time.
+    pub fn visibility_map(&self) -> grid::Grid<Sightlines> {
+        let (width, height) = (self.width(), self.height());
+        let mut visible = grid::Grid::from_fn(width, height, |_| Sightlines::NONE);
 
-            if value_compare >= value {
-                visible_right = false;
-                break;
-            }
+        for y in 0..height {
+            let row = (0..width).map(|x| Point::new(x, y));
+
+            self.mark_visible(&mut visible, Sightlines::WEST, row.clone());
+            self.mark_visible(&mut visible, Sightlines::EAST, row.rev());
         }
 
-        // Bottom
-        let mut visible_bottom = true;
-        for y_compare in (y + 1)..self.height {
-            let value_compare = self.data[y_compare][x];
+        for x in 0..width {
+            let column = (0..height).map(|y| Point::new(x, y));
 
-            if value_compare >= value {
-                visible_bottom = false;
-                break;
-            }
+            self.mark_visible(&mut visible, Sightlines::NORTH, column.clone());
+            self.mark_visible(&mut visible, Sightlines::SOUTH, column.rev());
         }
 
-        return visible_left || visible_top || visible_right || visible_bottom;
+        visible
     }
 
-    pub fn scenic_score(&self, x: usize, y: usize) -> usize {
-        let value = self.data[y][x];
+    /// How many trees can be seen from outside the grid, see [`Grid::visibility_map`].
+    pub fn visible_count(&self) -> usize {
+        self.visibility_map()
+            .iter()
+            .filter(|(_, sightlines)| sightlines.is_visible())
+            .count()
+    }
 
-        // ------------------------------------------------
-        // Left
-        // ------------------------------------------------
+    /// Mark the trees that can be seen from the `edge` where `line` starts.
+    pub(crate) fn mark_visible(
+        &self,
+        visible: &mut grid::Grid<Sightlines>,
+        edge: Sightlines,
+        line: impl Iterator<Item = Point>,
+    ) {
+        let width = self.width();
+        let line = line.map(|point| point.y * width + point.x);
+
+        sweep::mark_visible(self.trees.cells(), line, |idx| {
+            visible[Point::new(idx % width, idx / width)] |= edge
+        });
+    }
 
-        let mut scenic_score_left = 0;
+    /// The scenic score of every tree, see [`Grid::scenic_score_map_with`].
+    pub fn scenic_score_map(&self) -> grid::Grid<usize> {
+        self.scenic_score_map_with(&ScenicPolicy::default())
+    }
 
-        if x != 0 {
-            for x_compare in (0..x).rev() {
-                scenic_score_left += 1;
+    /// The score of every tree under a scenic policy, built from a
+    /// [`Grid::sight_distance_map`] per direction in O(width × height) time.
+    pub fn scenic_score_map_with(&self, policy: &ScenicPolicy) -> grid::Grid<usize> {
+        let distances: Vec<(Direction, grid::Grid<usize>)> = policy
+            .directions()
+            .map(|direction| (direction, self.sight_distance_map(direction)))
+            .collect();
 
-                let value_compare = self.data[y][x_compare];
-                if value_compare >= value {
-                    break;
-                }
-            }
+        grid::Grid::from_fn(self.width(), self.height(), |point| {
+            policy.score(distances.iter().map(|(d, map)| (*d, map[point])))
+        })
+    }
+
+    /// How far every tree can see in a direction. Each line of trees is
+    /// swept towards that direction's edge starting from the opposite one,
+    /// with a stack of the trees that could still block the view.
+    pub fn sight_distance_map(&self, direction: Direction) -> grid::Grid<usize> {
+        let mut distances = grid::Grid::from_fn(self.width(), self.height(), |_| 0);
+
+        // Lines start on the edge the trees are looking at.
+        let starts = self.trees.points().filter(|point| {
+            point
+                .step(direction)
+                .is_none_or(|p| !self.trees.contains(p))
+        });
+
+        for start in starts {
+            self.fill_sight_distances(&mut distances, direction, start);
         }
 
-        // ------------------------------------------------
-        // Top
-        // ------------------------------------------------
+        distances
+    }
 
-        let mut scenic_score_top = 0;
+    /// Fill in how far the trees on the line from `start`, on the edge
+    /// `direction` looks at, can see in that direction.
+    pub(crate) fn fill_sight_distances(
+        &self,
+        distances: &mut grid::Grid<usize>,
+        direction: Direction,
+        start: Point,
+    ) {
+        let width = self.width();
+        let line = std::iter::once(start)
+            .chain(
+                self.trees
+                    .ray(start, direction.opposite())
+                    .map(|(point, _)| point),
+            )
+            .map(|point| point.y * width + point.x);
+
+        sweep::viewing_distances(self.trees.cells(), line, |idx, distance| {
+            distances[Point::new(idx % width, idx / width)] = distance
+        });
+    }
 
-        if y != 0 {
-            for y_compare in (0..y).rev() {
-                scenic_score_top += 1;
+    /// The tree with the highest scenic score, with that score. Ties go to
+    /// the northernmost, then westernmost tree.
+    pub fn best_scenic_spot(&self) -> Option<(Point, usize)> {
+        self.best_scenic_spot_with(&ScenicPolicy::default())
+    }
 
-                let value_compare = self.data[y_compare][x];
-                if value_compare >= value {
-                    break;
-                }
-            }
-        }
+    /// The tree with the highest score under a scenic policy, with that score.
+    pub fn best_scenic_spot_with(&self, policy: &ScenicPolicy) -> Option<(Point, usize)> {
+        self.scenic_score_map_with(policy)
+            .iter()
+            .min_by_key(|&(point, score)| (Reverse(*score), point.y, point.x))
+            .map(|(point, score)| (point, *score))
+    }
+
+    /// The edges of the grid a tree can be seen from, looking along its
+    /// row and column. Trees on the border are always seen from their own edge.
+    pub fn sightlines(&self, x: usize, y: usize) -> Sightlines {
+        let point = Point::new(x, y);
+        let value = self.trees[point];
+
+        Direction::CARDINAL
+            .into_iter()
+            .filter(|direction| {
+                self.trees
+                    .ray(point, *direction)
+                    .all(|(_, height)| *height < value)
+            })
+            .fold(Sightlines::NONE, |sightlines, direction| {
+                sightlines | Sightlines::from_direction(direction)
+            })
+    }
 
-        // ------------------------------------------------
-        // Right
-        // ------------------------------------------------
+    /// The trees seen looking from a tree in a direction, up to and including
+    /// the first one that is at least as tall.
+    pub fn view(&self, from: Point, direction: Direction) -> impl Iterator<Item = Point> + '_ {
+        self.line_of_sight(from, self.trees[from], direction.into())
+    }
 
-        let mut scenic_score_right = 0;
+    /// How many trees can be seen looking from a tree in a direction.
+    pub fn sight_distance(&self, point: Point, direction: Direction) -> usize {
+        self.view(point, direction).count()
+    }
 
-        if x != self.width - 1 {
-            for x_compare in (x + 1)..self.width {
-                scenic_score_right += 1;
+    /// The trees an observer with eyes at `height` sees from `from` along a
+    /// slope, up to and including the first tree at least that tall.
+    pub fn line_of_sight(
+        &self,
+        from: Point,
+        height: Height,
+        slope: Slope,
+    ) -> impl Iterator<Item = Point> + '_ {
+        let mut blocked = false;
+
+        self.trees
+            .line(from, (slope.dx, slope.dy))
+            .take_while(move |(_, tree)| !std::mem::replace(&mut blocked, **tree >= height))
+            .map(|(point, _)| point)
+    }
 
-                let value_compare = self.data[y][x_compare];
-                if value_compare >= value {
-                    break;
-                }
-            }
+    /// Every tree an observer with eyes at `height` sees from a point of the
+    /// grid, looking in all eight directions.
+    pub fn visible_from(&self, observer: Point, height: Height) -> BTreeSet<Point> {
+        self.visible_along(observer, height, &Direction::ALL.map(Slope::from))
+    }
+
+    /// Every tree an observer with eyes at `height` sees from a point of the
+    /// grid, looking along each of the given slopes.
+    pub fn visible_along(
+        &self,
+        observer: Point,
+        height: Height,
+        slopes: &[Slope],
+    ) -> BTreeSet<Point> {
+        slopes
+            .iter()
+            .flat_map(|slope| self.line_of_sight(observer, height, *slope))
+            .collect()
+    }
+
+    /// Whether a tree can be seen from any edge of the grid.
+    pub fn is_visible(&self, x: usize, y: usize) -> bool {
+        self.sightlines(x, y).is_visible()
+    }
+
+    /// How far a tree can see to each edge, multiplied together.
+    pub fn scenic_score(&self, x: usize, y: usize) -> usize {
+        self.scenic_score_with(x, y, &ScenicPolicy::default())
+    }
+
+    /// A tree's score under a scenic policy.
+    pub fn scenic_score_with(&self, x: usize, y: usize, policy: &ScenicPolicy) -> usize {
+        let point = Point::new(x, y);
+
+        policy.score(
+            policy
+                .directions()
+                .map(|direction| (direction, self.sight_distance(point, direction))),
+        )
+    }
+}
+
+/// A set of the grid edges a tree can be seen from.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sightlines(u8);
+
+impl Sightlines {
+    pub const NONE: Sightlines = Sightlines(0);
+    pub const NORTH: Sightlines = Sightlines(1);
+    pub const EAST: Sightlines = Sightlines(1 << 1);
+    pub const SOUTH: Sightlines = Sightlines(1 << 2);
+    pub const WEST: Sightlines = Sightlines(1 << 3);
+    pub const ALL: Sightlines = Sightlines(0b1111);
+
+    /// The edge of the grid in a direction. Diagonals have no edge of their own.
+    pub fn from_direction(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Sightlines::NORTH,
+            Direction::East => Sightlines::EAST,
+            Direction::South => Sightlines::SOUTH,
+            Direction::West => Sightlines::WEST,
+            _ => Sightlines::NONE,
         }
+    }
+
+    /// Whether every edge in `other` is also in this set.
+    pub fn contains(self, other: Sightlines) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Forget the edges in `other`.
+    pub fn remove(&mut self, other: Sightlines) {
+        self.0 &= !other.0;
+    }
 
-        // ------------------------------------------------
-        // Bottom
-        // ------------------------------------------------
+    /// Whether the tree can be seen from at least one edge.
+    pub fn is_visible(self) -> bool {
+        self != Sightlines::NONE
+    }
 
-        let mut scenic_score_bottom = 0;
+    /// The directions of the edges in this set, clockwise from north.
+    pub fn directions(self) -> impl Iterator<Item = Direction> {
+        Direction::CARDINAL
+            .into_iter()
+            .filter(move |direction| self.contains(Sightlines::from_direction(*direction)))
+    }
+}
 
-        if y != self.height - 1 {
-            for y_compare in (y + 1)..self.height {
-                scenic_score_bottom += 1;
+impl BitOr for Sightlines {
+    type Output = Sightlines;
 
-                let value_compare = self.data[y_compare][x];
-                if value_compare >= value {
-                    break;
-                }
-            }
+    fn bitor(self, other: Sightlines) -> Sightlines {
+        Sightlines(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for Sightlines {
+    fn bitor_assign(&mut self, other: Sightlines) {
+        self.0 |= other.0;
+    }
+}
+
+/// A direction of sight as a whole step of `dx` trees east and `dy` trees
+/// south, like `(2, -1)` for two trees east for every tree north. Only the
+/// trees exactly on the line are looked at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Slope {
+    dx: isize,
+    dy: isize,
+}
+
+impl Slope {
+    /// A slope through `(dx, dy)`, reduced to its smallest step so `(2, 2)`
+    /// is the same as `(1, 1)`. There is no slope through `(0, 0)`.
+    pub fn new(dx: isize, dy: isize) -> Option<Self> {
+        let divisor = gcd(dx.unsigned_abs(), dy.unsigned_abs()) as isize;
+
+        match divisor {
+            0 => None,
+            _ => Some(Self {
+                dx: dx / divisor,
+                dy: dy / divisor,
+            }),
         }
+    }
+}
+
+impl From<Direction> for Slope {
+    fn from(direction: Direction) -> Self {
+        let (dx, dy) = direction.offset();
 
-        return scenic_score_left * scenic_score_top * scenic_score_right * scenic_score_bottom;
+        Self { dx, dy }
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    const TEST_GRID: &str = "30373\n25512\n65332\n33549\n35390";
+    use std::collections::BTreeSet;
+
+    use shared::grid::{Direction, Point};
+    use shared::random::Rng;
+
+    use super::{Grid, Sightlines, Slope};
+    use crate::decode::Decoder;
+    use crate::scenic::ScenicPolicy;
+    use crate::TEST_GRID;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn it_determines_if_a_point_is_visible() {
-        let grid = super::Grid::new(TEST_GRID);
+        let grid = Grid::new(TEST_GRID).unwrap();
 
         assert_eq!(true, grid.is_visible(1, 1));
         assert_eq!(true, grid.is_visible(2, 1));
@@ -172,11 +421,251 @@ mod tests {
         assert_eq!(true, grid.is_visible(4, 2));
     }
 
+    #[test]
+    fn it_maps_the_same_visibility_as_checking_each_tree() {
+        for grid in [Grid::new(TEST_GRID).unwrap(), Grid::random(45, 60, 42)] {
+            let visible = grid.visibility_map();
+
+            for (point, sightlines) in visible.iter() {
+                assert_eq!(
+                    grid.sightlines(point.x, point.y),
+                    *sightlines,
+                    "{:?}",
+                    point
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn it_reports_the_edges_a_tree_is_visible_from() {
+        let grid = Grid::new(TEST_GRID).unwrap();
+
+        assert_eq!(Sightlines::NORTH | Sightlines::WEST, grid.sightlines(1, 1));
+        assert_eq!(Sightlines::EAST, grid.sightlines(3, 2));
+        assert_eq!(Sightlines::NONE, grid.sightlines(2, 2));
+        assert!(grid.sightlines(1, 1).contains(Sightlines::NORTH));
+        assert!(!grid.sightlines(1, 1).contains(Sightlines::SOUTH));
+        assert_eq!(
+            vec![Direction::North, Direction::West],
+            grid.sightlines(1, 1).directions().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn it_sees_every_border_tree_from_its_own_edges() {
+        // Tall trees everywhere, so border trees are only visible from their own edges.
+        for (width, height) in [(5, 5), (7, 3), (2, 6), (1, 4), (4, 1), (1, 1)] {
+            let input = vec!["9".repeat(width); height].join("\n");
+            let grid = Grid::new(&input).unwrap();
+            let visible = grid.visibility_map();
+
+            for y in 0..height {
+                for x in 0..width {
+                    let mut expected = Sightlines::NONE;
+
+                    if y == 0 {
+                        expected |= Sightlines::NORTH;
+                    }
+                    if x == width - 1 {
+                        expected |= Sightlines::EAST;
+                    }
+                    if y == height - 1 {
+                        expected |= Sightlines::SOUTH;
+                    }
+                    if x == 0 {
+                        expected |= Sightlines::WEST;
+                    }
+
+                    assert_eq!(expected, grid.sightlines(x, y), "({}, {})", x, y);
+                    assert_eq!(expected, visible[Point::new(x, y)], "({}, {})", x, y);
+                }
+            }
+        }
+    }
+
     #[test]
     fn it_calculates_the_scenic_score() {
-        let grid = super::Grid::new(TEST_GRID);
+        let grid = Grid::new(TEST_GRID).unwrap();
 
         assert_eq!(4, grid.scenic_score(2, 1));
         assert_eq!(8, grid.scenic_score(2, 3));
     }
+
+    #[test]
+    fn it_maps_the_same_scenic_scores_as_scoring_each_tree() {
+        for grid in [Grid::new(TEST_GRID).unwrap(), Grid::random(60, 45, 42)] {
+            let scores = grid.scenic_score_map();
+
+            for (point, score) in scores.iter() {
+                assert_eq!(grid.scenic_score(point.x, point.y), *score, "{:?}", point);
+            }
+        }
+    }
+
+    #[test]
+    fn it_maps_the_same_scores_as_scoring_each_tree_for_any_policy() {
+        let grid = Grid::random(40, 30, 42);
+
+        for spec in ["sum", "min,diagonals", "max,ne=3", "product,diagonals,w=2"] {
+            let policy = ScenicPolicy::parse(spec).unwrap();
+            let scores = grid.scenic_score_map_with(&policy);
+
+            for (point, score) in scores.iter() {
+                let expected = grid.scenic_score_with(point.x, point.y, &policy);
+
+                assert_eq!(expected, *score, "{} at {:?}", spec, point);
+            }
+        }
+    }
+
+    #[test]
+    fn it_finds_the_best_scenic_spot() {
+        let grid = Grid::new(TEST_GRID).unwrap();
+
+        assert_eq!(Some((Point::new(2, 3), 8)), grid.best_scenic_spot());
+    }
+
+    #[test]
+    fn it_sees_in_all_eight_directions() {
+        let grid = Grid::new(TEST_GRID).unwrap();
+        let seen = grid.visible_from(Point::new(2, 3), 5);
+
+        let expected: BTreeSet<Point> = [
+            (2, 2),
+            (2, 1),
+            (3, 2),
+            (4, 1),
+            (3, 3),
+            (4, 3),
+            (3, 4),
+            (2, 4),
+            (1, 4),
+            (1, 3),
+            (0, 3),
+            (1, 2),
+        ]
+        .map(Point::from)
+        .into();
+
+        assert_eq!(expected, seen);
+        assert_eq!(3, grid.visible_from(Point::new(0, 0), 0).len());
+    }
+
+    #[test]
+    fn it_sees_along_any_slope() {
+        let grid = Grid::new(TEST_GRID).unwrap();
+        let slope = Slope::new(2, 1).unwrap();
+
+        assert_eq!(Slope::new(4, 2), Some(slope));
+        assert_eq!(None, Slope::new(0, 0));
+        assert_eq!(
+            vec![Point::new(2, 1), Point::new(4, 2)],
+            grid.line_of_sight(Point::new(0, 0), 9, slope)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Point::new(2, 1)],
+            grid.line_of_sight(Point::new(0, 0), 4, slope)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn it_measures_sight_distances() {
+        let grid = Grid::new(TEST_GRID).unwrap();
+        let point = Point::new(2, 3);
+
+        assert_eq!(2, grid.sight_distance(point, Direction::North));
+        assert_eq!(1, grid.sight_distance(point, Direction::South));
+        assert_eq!(1, grid.sight_distance(point, Direction::SouthEast));
+        assert_eq!(0, grid.sight_distance(Point::new(0, 2), Direction::West));
+    }
+
+    #[test]
+    fn it_grows_the_same_random_forest_for_a_seed() {
+        let forest = Grid::random(7, 3, 1);
+
+        assert_eq!((7, 3), (forest.width(), forest.height()));
+        assert_eq!(forest.trees(), Grid::random(7, 3, 1).trees());
+        assert_ne!(forest.trees(), Grid::random(7, 3, 2).trees());
+        assert!(forest.trees().iter().all(|(_, height)| *height <= 9));
+    }
+
+    #[test]
+    fn it_agrees_with_brute_force_on_random_forests() {
+        let mut rng = Rng::new(2022);
+
+        for seed in 0..3000 {
+            // Single rows and columns are where edge handling goes wrong.
+            let (width, height) = match seed % 4 {
+                0 => (1, rng.range(1..20)),
+                1 => (rng.range(1..20), 1),
+                _ => (rng.range(1..12), rng.range(1..12)),
+            };
+            let grid = Grid::random(width, height, seed);
+            let visible = grid.visibility_map();
+            let scores = grid.scenic_score_map();
+
+            for (point, score) in scores.iter() {
+                let (x, y) = (point.x, point.y);
+
+                assert_eq!(
+                    grid.sightlines(x, y),
+                    visible[point],
+                    "seed {} at {:?}",
+                    seed,
+                    point
+                );
+                assert_eq!(grid.is_visible(x, y), visible[point].is_visible());
+                assert_eq!(
+                    grid.scenic_score(x, y),
+                    *score,
+                    "seed {} at {:?}",
+                    seed,
+                    point
+                );
+            }
+
+            let best = (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(|(x, y)| grid.scenic_score(x, y))
+                .max();
+
+            assert_eq!(best, grid.best_scenic_spot().map(|(_, score)| score));
+
+            let visible_count = (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .filter(|&(x, y)| grid.is_visible(x, y))
+                .count();
+
+            assert_eq!(visible_count, grid.visible_count(), "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn it_rejects_malformed_grids() {
+        assert!(Grid::new("").is_err());
+        assert!(Grid::new("303\n2x5").is_err());
+        assert!(Grid::new("303\n25").is_err());
+    }
+
+    #[test]
+    fn it_reads_taller_trees_with_other_decoders() {
+        let digits = Grid::new(TEST_GRID).unwrap();
+        let letters = Grid::parse("dadhd\ncffbc\ngfddc\nddfej\ndfdja", Decoder::Letters).unwrap();
+        let numbers = Grid::parse(
+            "300,0,300,700,300\n200,500,500,100,200\n600,500,300,300,200\n300,300,500,400,900\n300,500,300,900,0",
+            Decoder::Numbers,
+        )
+        .unwrap();
+
+        // Shifting or scaling every height changes neither answer.
+        for forest in [&letters, &numbers] {
+            assert_eq!(digits.visibility_map(), forest.visibility_map());
+            assert_eq!(digits.best_scenic_spot(), forest.best_scenic_spot());
+        }
+
+        assert_eq!(900, numbers.trees()[Point::new(4, 3)]);
+    }
 }
@@ -0,0 +1,141 @@
+pub mod decode;
+pub mod grid;
+pub mod options;
+#[cfg(feature = "parallel")]
+mod parallel;
+pub mod planner;
+pub mod render;
+pub mod scenic;
+pub mod show;
+pub mod stats;
+mod sweep;
+pub mod volume;
+
+use std::time::Duration;
+
+use decode::Decoder;
+use grid::Grid;
+use shared::bench::Phase;
+use shared::{Answer, Part, Solution, SolvedPart, Solver};
+
+pub struct Day8;
+
+/// Day 8 reading the tree heights with any [`Decoder`], as picked with
+/// `--heights` in [`options::Extras`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Day8With(pub Decoder);
+
+impl Day8With {
+    fn solve_part(grid: &Grid, part: Part) -> shared::Result<Answer> {
+        match part {
+            Part::One => Day8::part1(grid),
+            Part::Two => Day8::part2(grid),
+        }
+    }
+}
+
+impl Solver for Day8With {
+    fn day(&self) -> u8 {
+        Day8::DAY
+    }
+
+    fn solve(&self, input: &str, part: Part) -> shared::Result<Answer> {
+        Self::solve_part(&Grid::parse(input, self.0)?, part)
+    }
+
+    fn solve_parts(&self, input: &str, parts: &[Part]) -> shared::Result<Vec<SolvedPart>> {
+        let grid = Grid::parse(input, self.0)?;
+
+        Ok(shared::solve_each(&grid, parts, Self::solve_part))
+    }
+
+    fn time_phases(&self, input: &str, parts: &[Part]) -> shared::Result<Vec<(Phase, Duration)>> {
+        shared::time_each(
+            input,
+            parts,
+            |input| Grid::parse(input, self.0),
+            Self::solve_part,
+        )
+    }
+}
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Parsed = Grid;
+
+    fn parse(input: &str) -> shared::Result<Self::Parsed> {
+        Grid::new(input)
+    }
+
+    // ------------------------------------------------
+    // Part 1
+    // ------------------------------------------------
+
+    #[cfg(feature = "parallel")]
+    fn part1(grid: &Self::Parsed) -> shared::Result<Answer> {
+        Ok(grid.par_visible_count().into())
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn part1(grid: &Self::Parsed) -> shared::Result<Answer> {
+        Ok(grid.visible_count().into())
+    }
+
+    // ------------------------------------------------
+    // Part 2
+    // ------------------------------------------------
+
+    fn part2(grid: &Self::Parsed) -> shared::Result<Answer> {
+        #[cfg(feature = "parallel")]
+        let best = grid.par_best_scenic_spot();
+        #[cfg(not(feature = "parallel"))]
+        let best = grid.best_scenic_spot();
+
+        match best {
+            Some((_, score)) => Ok(score.into()),
+            None => Err(shared::Error::unsolvable("The grid has no trees")),
+        }
+    }
+}
+
+/// The forest from the puzzle statement, for the tests of every module.
+#[cfg(test)]
+pub(crate) const TEST_GRID: &str = include_str!("../examples/example.txt");
+
+#[cfg(test)]
+mod tests {
+    use shared::{Answer, Part, Solver};
+
+    use super::decode::Decoder;
+    use super::{Day8, Day8With, TEST_GRID};
+
+    shared::example_tests!(super::Day8, example);
+
+    #[test]
+    fn it_solves_forests_with_other_heights() {
+        let letters: String = TEST_GRID
+            .chars()
+            .map(|c| match c.to_digit(10) {
+                Some(digit) => (b'a' + digit as u8) as char,
+                None => c,
+            })
+            .collect();
+        let numbers = "300 3000 3\n1 1 3000\n";
+
+        for part in Part::ALL {
+            assert_eq!(
+                Day8.solve(TEST_GRID, part).unwrap(),
+                Day8With(Decoder::Letters).solve(&letters, part).unwrap()
+            );
+        }
+
+        assert_eq!(
+            Answer::from(6),
+            Day8With(Decoder::Numbers)
+                .solve(numbers, Part::One)
+                .unwrap()
+        );
+        assert!(Day8.solve(&letters, Part::One).is_err());
+    }
+}
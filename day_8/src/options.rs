@@ -0,0 +1,278 @@
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+
+use shared::output::Format;
+use shared::runner::{Extension, Options};
+use shared::Solver;
+
+use crate::decode::Decoder;
+use crate::grid::Grid;
+use crate::render::{self, Map};
+use crate::scenic::ScenicPolicy;
+use crate::show;
+use crate::stats::{self, Stats};
+use crate::Day8With;
+
+/// Flags only the `day_8` binary understands:
+///   - `--render <map>=<path>` draws a [`Map`] of the forest, `visibility` or
+///     `scenic`, to any PNG or PPM file
+///   - `--show` prints the forest, in color when stdout is a terminal, and
+///     only with `--format text`
+///   - `--policy <policy>` finds the best scenic spot under another
+///     [`ScenicPolicy`], which `--render`, `--show` and `--stats` use too
+///   - `--stats` prints a [`Stats`] report, in the `--format` of the answers,
+///     see [`Stats::report`]
+///   - `--stats-out <path>` writes the report to a file instead, which a CSV
+///     report needs to stay apart from the answers
+///   - `--top <N>` lists the `N` best scenic spots in the report
+///   - `--heights <digits|letters|numbers>` reads the tree heights with
+///     another [`Decoder`], for the answers as well as everything above
+#[derive(Debug, Default)]
+pub struct Extras {
+    day: Day8With,
+    renders: Vec<(Map, PathBuf)>,
+    show: bool,
+    policy: Option<ScenicPolicy>,
+    stats: bool,
+    stats_out: Option<PathBuf>,
+    top: Option<usize>,
+}
+
+impl Extension for Extras {
+    fn flag(&mut self, flag: &str, args: &mut dyn Iterator<Item = String>) -> shared::Result<bool> {
+        match flag {
+            "--render" => {
+                let value = args.next().unwrap_or_default();
+                let (map, path) = value
+                    .split_once('=')
+                    .and_then(|(name, path)| Some((Map::from_name(name)?, path)))
+                    .filter(|(_, path)| !path.is_empty())
+                    .ok_or_else(|| {
+                        shared::Error::invalid(format!(
+                            "Invalid render `{}`, expected `visibility=<file>` or `scenic=<file>`",
+                            value
+                        ))
+                    })?;
+
+                self.renders.push((map, PathBuf::from(path)));
+            }
+            "--show" => self.show = true,
+            "--policy" => {
+                let spec = args.next().ok_or_else(|| {
+                    shared::Error::invalid("Missing policy for `--policy`, e.g. `sum,diagonals`")
+                })?;
+
+                self.policy = Some(ScenicPolicy::parse(&spec)?);
+            }
+            "--stats" => self.stats = true,
+            "--stats-out" => {
+                let path = args.next().ok_or_else(|| {
+                    shared::Error::invalid("Missing file for `--stats-out`, e.g. `stats.csv`")
+                })?;
+
+                self.stats = true;
+                self.stats_out = Some(PathBuf::from(path));
+            }
+            "--top" => {
+                let value = args.next().unwrap_or_default();
+                let top = value.parse().map_err(|_| {
+                    shared::Error::invalid(format!(
+                        "Invalid spot count `{}` for `--top`, expected a number",
+                        value
+                    ))
+                })?;
+
+                self.top = Some(top);
+            }
+            "--heights" => {
+                let value = args.next().unwrap_or_default();
+                let decoder = Decoder::from_name(&value).ok_or_else(|| {
+                    shared::Error::invalid(format!(
+                        "Invalid heights `{}`, expected digits, letters or numbers",
+                        value
+                    ))
+                })?;
+
+                self.day = Day8With(decoder);
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    fn check(&self, options: &Options) -> shared::Result<()> {
+        if self.show && options.format != Format::Text {
+            return Err(shared::Error::invalid(format!(
+                "`--show` draws the forest as text and cannot be used with `--format {}`",
+                options.format
+            )));
+        }
+
+        if self.stats && self.stats_out.is_none() && options.format == Format::Csv {
+            return Err(shared::Error::invalid(
+                "The `--stats` report has columns of its own, write it with `--stats-out <file>` when using `--format csv`",
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn solver(&self) -> Option<&dyn Solver> {
+        Some(&self.day)
+    }
+
+    fn run(&self, input: &str, format: Format) -> shared::Result<()> {
+        if self.renders.is_empty() && !self.show && self.policy.is_none() && !self.stats {
+            return Ok(());
+        }
+
+        let forest = Grid::parse(input, self.day.0)?;
+        let policy = self.policy.unwrap_or_default();
+
+        if let Some(policy) = &self.policy {
+            let line = match forest.best_scenic_spot_with(policy) {
+                Some((point, score)) => format!(
+                    "Best scenic spot ({}): ({}, {}) scoring {}",
+                    policy, point.x, point.y, score
+                ),
+                None => format!("Best scenic spot ({}): none", policy),
+            };
+
+            // Keep stdout to the rows of the chosen format.
+            match format {
+                Format::Text => println!("{}", line),
+                _ => eprintln!("{}", line),
+            }
+        }
+
+        if self.show {
+            println!();
+            print!(
+                "{}",
+                show::show(&forest, &policy, io::stdout().is_terminal())
+            );
+        }
+
+        if self.stats {
+            let report =
+                Stats::new(&forest, &policy, self.top.unwrap_or(stats::DEFAULT_TOP)).report(format);
+
+            match &self.stats_out {
+                Some(path) => {
+                    fs::write(path, report).map_err(|e| {
+                        shared::Error::Io(io::Error::new(
+                            e.kind(),
+                            format!("Unable to write `{}`: {}", path.display(), e),
+                        ))
+                    })?;
+                    eprintln!("Wrote {}", path.display());
+                }
+                None if format == Format::Text => print!("\n{}", report),
+                None => print!("{}", report),
+            }
+        }
+
+        for (map, path) in &self.renders {
+            render::render(&forest, *map, &policy, path)?;
+            eprintln!("Wrote {}", path.display());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::runner::{Extension, Options};
+    use shared::{Answer, Part};
+
+    use std::path::PathBuf;
+
+    use super::Extras;
+    use crate::render::Map;
+
+    fn parse(args: &[&str]) -> shared::Result<(Options, Extras)> {
+        let args = args.iter().map(|arg| arg.to_string());
+        let mut extras = Extras::default();
+
+        Options::parse_with(args, &mut extras).map(|options| (options, extras))
+    }
+
+    #[test]
+    fn it_only_shows_the_forest_as_text() {
+        assert!(parse(&["--show"]).is_ok());
+        assert!(parse(&["--show", "--format", "text"]).is_ok());
+
+        match parse(&["--show", "--format", "json"]) {
+            Err(shared::Error::Invalid(message)) => assert!(message.contains("--show")),
+            other => panic!("expected an invalid option, got {:?}", other.map(|_| ())),
+        }
+        assert!(parse(&["--format", "csv", "--show"]).is_err());
+    }
+
+    #[test]
+    fn it_keeps_csv_stats_out_of_the_answers() {
+        assert!(parse(&["--stats", "--format", "json"]).is_ok());
+        assert!(parse(&["--stats", "--format", "csv"]).is_err());
+        assert!(parse(&["--format", "csv", "--stats", "--stats-out", "stats.csv"]).is_ok());
+
+        let (_, extras) = parse(&["--stats-out", "stats.txt"]).unwrap();
+        assert!(extras.stats);
+        assert_eq!(Some(PathBuf::from("stats.txt")), extras.stats_out);
+        assert!(parse(&["--stats-out"]).is_err());
+    }
+
+    #[test]
+    fn it_names_the_map_to_render() {
+        let (_, extras) = parse(&[
+            "--render",
+            "visibility=out.png",
+            "--render",
+            "scenic=/tmp/forest-visibility.ppm",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                (Map::Visibility, PathBuf::from("out.png")),
+                (Map::Scenic, PathBuf::from("/tmp/forest-visibility.ppm"))
+            ],
+            extras.renders
+        );
+        assert!(parse(&["--render", "scenic.png"]).is_err());
+        assert!(parse(&["--render", "heights=out.png"]).is_err());
+        assert!(parse(&["--render", "scenic="]).is_err());
+    }
+
+    #[test]
+    fn it_solves_with_the_chosen_heights() {
+        let (_, digits) = parse(&["--heights", "digits"]).unwrap();
+        let (_, letters) = parse(&["--heights", "letters"]).unwrap();
+        let (_, numbers) = parse(&["--heights", "numbers"]).unwrap();
+
+        assert_eq!(
+            Answer::from(4),
+            digits.solver().unwrap().solve("12\n34", Part::One).unwrap()
+        );
+        assert_eq!(
+            Answer::from(4),
+            letters
+                .solver()
+                .unwrap()
+                .solve("ab\ncd", Part::One)
+                .unwrap()
+        );
+        assert_eq!(
+            Answer::from(4),
+            numbers
+                .solver()
+                .unwrap()
+                .solve("10 20\n30 40", Part::One)
+                .unwrap()
+        );
+        assert!(parse(&["--heights", "hex"]).is_err());
+        assert!(parse(&["--heights"]).is_err());
+    }
+}
@@ -0,0 +1,108 @@
+//! Whole-forest answers computed on all cores, for forests far larger than
+//! the puzzle input. Every column is swept in parallel first, then every row,
+//! which combines its own sweeps with the column results. The answers are
+//! the same as those of the sequential [`Grid::visibility_map`] and
+//! [`Grid::best_scenic_spot`].
+
+use std::cmp::Reverse;
+
+use rayon::prelude::*;
+use shared::grid::Point;
+
+use crate::grid::{Grid, Height};
+use crate::sweep;
+
+impl Grid {
+    /// How many trees can be seen from outside the grid.
+    pub fn par_visible_count(&self) -> usize {
+        let (width, height) = (self.width(), self.height());
+
+        // Whether each tree is visible from the north or south, column by column.
+        let mut columns = vec![false; width * height];
+
+        columns
+            .par_chunks_mut(height)
+            .enumerate()
+            .for_each(|(x, visible)| {
+                let column: Vec<Height> = self.trees().column(x).copied().collect();
+
+                sweep::mark_visible(&column, 0..height, |y| visible[y] = true);
+                sweep::mark_visible(&column, (0..height).rev(), |y| visible[y] = true);
+            });
+
+        self.trees()
+            .cells()
+            .par_chunks(width)
+            .enumerate()
+            .map(|(y, row)| {
+                let mut visible = vec![false; width];
+
+                sweep::mark_visible(row, 0..width, |x| visible[x] = true);
+                sweep::mark_visible(row, (0..width).rev(), |x| visible[x] = true);
+
+                (0..width)
+                    .filter(|&x| visible[x] || columns[x * height + y])
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The tree with the highest scenic score, with that score. Ties go to
+    /// the northernmost, then westernmost tree.
+    pub fn par_best_scenic_spot(&self) -> Option<(Point, usize)> {
+        let (width, height) = (self.width(), self.height());
+
+        // How far each tree sees to the north times how far it sees to the south.
+        let mut columns = vec![1; width * height];
+
+        columns
+            .par_chunks_mut(height)
+            .enumerate()
+            .for_each(|(x, scores)| {
+                let column: Vec<Height> = self.trees().column(x).copied().collect();
+
+                sweep::viewing_distances(&column, 0..height, |y, d| scores[y] *= d);
+                sweep::viewing_distances(&column, (0..height).rev(), |y, d| scores[y] *= d);
+            });
+
+        self.trees()
+            .cells()
+            .par_chunks(width)
+            .enumerate()
+            .filter_map(|(y, row)| {
+                let mut scores = vec![1; width];
+
+                sweep::viewing_distances(row, 0..width, |x, d| scores[x] *= d);
+                sweep::viewing_distances(row, (0..width).rev(), |x, d| scores[x] *= d);
+
+                (0..width)
+                    .map(|x| (Point::new(x, y), scores[x] * columns[x * height + y]))
+                    .min_by_key(|&(point, score)| (Reverse(score), point.x))
+            })
+            .min_by_key(|&(point, score)| (Reverse(score), point.y, point.x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+
+    #[test]
+    fn it_matches_the_sequential_answers() {
+        let shapes = [(5, 5), (1, 1), (1, 17), (17, 1), (40, 90), (120, 70)];
+
+        for (seed, (height, width)) in shapes.into_iter().enumerate() {
+            let grid = Grid::random(width, height, seed as u64);
+            let visible = grid.visible_count();
+
+            assert_eq!(visible, grid.par_visible_count(), "{}x{}", width, height);
+            assert_eq!(
+                grid.best_scenic_spot(),
+                grid.par_best_scenic_spot(),
+                "{}x{}",
+                width,
+                height
+            );
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Keeping the answers up to date while trees are planted and cut down.
+//!
+//! Changing one tree only changes what can be seen along its row and its
+//! column, so that is all that is swept again after each change.
+
+use std::cmp::Reverse;
+use std::collections::BTreeSet;
+
+use shared::grid::{self, Direction, Point};
+
+use crate::grid::{Grid, Height, Sightlines};
+
+/// A forest with its visible trees and scenic scores, for "what if" questions
+/// like how the view changes when a tree is cut down.
+pub struct Planner {
+    forest: Grid,
+    visible: grid::Grid<Sightlines>,
+    visible_count: usize,
+    /// How far every tree sees, in [`Direction::CARDINAL`] order.
+    distances: [grid::Grid<usize>; 4],
+    scores: grid::Grid<usize>,
+    /// Every tree's score, ordered so the best scenic spot comes last.
+    ranking: BTreeSet<(usize, Reverse<usize>, Reverse<usize>)>,
+}
+
+impl Planner {
+    /// Work out the visible trees and scenic scores of a whole forest once.
+    pub fn new(forest: Grid) -> Self {
+        let visible = forest.visibility_map();
+        let visible_count = forest.visible_count();
+        let distances = Direction::CARDINAL.map(|direction| forest.sight_distance_map(direction));
+        let scores = grid::Grid::from_fn(forest.width(), forest.height(), |point| {
+            distances.iter().map(|map| map[point]).product()
+        });
+        let ranking = scores
+            .iter()
+            .map(|(point, score)| rank(point, *score))
+            .collect();
+
+        Self {
+            forest,
+            visible,
+            visible_count,
+            distances,
+            scores,
+            ranking,
+        }
+    }
+
+    pub fn forest(&self) -> &Grid {
+        &self.forest
+    }
+
+    pub fn into_forest(self) -> Grid {
+        self.forest
+    }
+
+    /// How many trees can be seen from outside the grid.
+    pub fn visible_count(&self) -> usize {
+        self.visible_count
+    }
+
+    /// The tree with the highest scenic score, with that score. Ties go to
+    /// the northernmost, then westernmost tree, as with [`Grid::best_scenic_spot`].
+    pub fn best_scenic_spot(&self) -> Option<(Point, usize)> {
+        self.ranking
+            .last()
+            .map(|&(score, Reverse(y), Reverse(x))| (Point::new(x, y), score))
+    }
+
+    /// Plant, grow or cut down the tree at `(x, y)`, returning its old height.
+    /// Only the tree's row and column are swept again, in O(width + height) time
+    /// plus keeping the scores in order.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `(x, y)` is outside of the grid.
+    pub fn set_height(&mut self, x: usize, y: usize, height: Height) -> shared::Result<Height> {
+        let old = self.forest.set_height(x, y, height)?;
+
+        if old == height {
+            return Ok(old);
+        }
+
+        let (width, depth) = (self.forest.width(), self.forest.height());
+        let row: Vec<Point> = (0..width).map(|x| Point::new(x, y)).collect();
+        let column: Vec<Point> = (0..depth).map(|y| Point::new(x, y)).collect();
+        let changed = || row.iter().chain(column.iter().filter(|p| p.y != y));
+
+        for point in changed() {
+            self.visible_count -= self.visible[*point].is_visible() as usize;
+            self.ranking.remove(&rank(*point, self.scores[*point]));
+        }
+
+        for point in &row {
+            self.visible[*point].remove(Sightlines::WEST | Sightlines::EAST);
+        }
+
+        for point in &column {
+            self.visible[*point].remove(Sightlines::NORTH | Sightlines::SOUTH);
+        }
+
+        self.forest
+            .mark_visible(&mut self.visible, Sightlines::WEST, row.iter().copied());
+        self.forest.mark_visible(
+            &mut self.visible,
+            Sightlines::EAST,
+            row.iter().rev().copied(),
+        );
+        self.forest
+            .mark_visible(&mut self.visible, Sightlines::NORTH, column.iter().copied());
+        self.forest.mark_visible(
+            &mut self.visible,
+            Sightlines::SOUTH,
+            column.iter().rev().copied(),
+        );
+
+        for (direction, distances) in Direction::CARDINAL.into_iter().zip(&mut self.distances) {
+            // Lines start on the edge the trees are looking at.
+            let start = match direction {
+                Direction::North => Point::new(x, 0),
+                Direction::South => Point::new(x, depth - 1),
+                Direction::East => Point::new(width - 1, y),
+                _ => Point::new(0, y),
+            };
+
+            self.forest
+                .fill_sight_distances(distances, direction, start);
+        }
+
+        for point in changed() {
+            let score = self.distances.iter().map(|map| map[*point]).product();
+
+            self.scores[*point] = score;
+            self.visible_count += self.visible[*point].is_visible() as usize;
+            self.ranking.insert(rank(*point, score));
+        }
+
+        Ok(old)
+    }
+}
+
+/// The key a tree is ranked by: higher scores first, then northernmost, then westernmost.
+fn rank(point: Point, score: usize) -> (usize, Reverse<usize>, Reverse<usize>) {
+    (score, Reverse(point.y), Reverse(point.x))
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::grid::Point;
+    use shared::random::Rng;
+
+    use super::Planner;
+    use crate::grid::{Grid, Height};
+    use crate::TEST_GRID;
+
+    fn answers(forest: &Grid) -> (usize, Option<(Point, usize)>) {
+        let visible = forest.visible_count();
+
+        (visible, forest.best_scenic_spot())
+    }
+
+    #[test]
+    fn it_updates_the_answers_when_trees_change() {
+        let mut planner = Planner::new(Grid::new(TEST_GRID).unwrap());
+
+        assert_eq!(21, planner.visible_count());
+        assert_eq!(Some((Point::new(2, 3), 8)), planner.best_scenic_spot());
+
+        // Cutting down the tree in the middle of the forest.
+        assert_eq!(
+            Ok(3),
+            planner.set_height(2, 2, 0).map_err(|e| e.to_string())
+        );
+        assert_eq!(answers(planner.forest()).0, planner.visible_count());
+        assert_eq!(answers(planner.forest()).1, planner.best_scenic_spot());
+
+        assert!(planner.set_height(5, 0, 1).is_err());
+    }
+
+    #[test]
+    fn it_matches_a_full_recount_after_random_changes() {
+        for seed in 0..50 {
+            let (width, height) = (1 + seed % 7, 1 + seed / 7);
+            let mut planner = Planner::new(Grid::random(width, height, seed as u64));
+            let mut rng = Rng::new(seed as u64);
+
+            for _ in 0..40 {
+                let x = rng.below(width as u64) as usize;
+                let y = rng.below(height as u64) as usize;
+                planner.set_height(x, y, rng.below(10) as Height).unwrap();
+
+                let (visible, best) = answers(planner.forest());
+
+                assert_eq!(visible, planner.visible_count(), "seed {}", seed);
+                assert_eq!(best, planner.best_scenic_spot(), "seed {}", seed);
+            }
+        }
+    }
+}
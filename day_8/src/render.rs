@@ -0,0 +1,157 @@
+//! Pictures of the forest, to eyeball the input and the maps built from it.
+
+use std::path::Path;
+
+use shared::grid;
+use shared::image::{self, Rgb};
+
+use crate::grid::Grid;
+use crate::scenic::ScenicPolicy;
+
+/// How many pixels wide and tall every tree is drawn.
+const TREE_SIZE: usize = 5;
+
+/// Colors for the scenic score, from the lowest to the highest score.
+const SCENIC_RAMP: [Rgb; 4] = [[0, 0, 0], [0, 0, 255], [255, 0, 0], [255, 255, 0]];
+
+/// Which picture to draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Map {
+    /// Tree heights in grey, with trees visible from outside the forest in green.
+    Visibility,
+    /// Scenic scores as a color ramp, with the best spot in white.
+    Scenic,
+}
+
+impl Map {
+    /// Parse a map name as given to `--render`, `visibility` or `scenic`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "visibility" => Some(Map::Visibility),
+            "scenic" => Some(Map::Scenic),
+            _ => None,
+        }
+    }
+}
+
+/// Draw a map of the forest, one pixel per tree. Scenic scores follow `policy`.
+pub fn draw(forest: &Grid, map: Map, policy: &ScenicPolicy) -> grid::Grid<Rgb> {
+    match map {
+        Map::Visibility => draw_visibility(forest),
+        Map::Scenic => draw_scenic(forest, policy),
+    }
+}
+
+/// Draw a map and write it as a PNG or PPM image, depending on the extension.
+///
+/// # Errors
+///
+/// This function will return an error if the extension is not an image
+/// format, or the image cannot be written.
+pub fn render(forest: &Grid, map: Map, policy: &ScenicPolicy, path: &Path) -> shared::Result<()> {
+    image::save(&image::scale(&draw(forest, map, policy), TREE_SIZE), path)
+}
+
+fn draw_visibility(forest: &Grid) -> grid::Grid<Rgb> {
+    let visible = forest.visibility_map();
+
+    let trees = forest.trees();
+    // Puzzle heights step by 25 from 30 to 255, taller forests are scaled to fit.
+    let tallest = trees
+        .iter()
+        .map(|(_, h)| usize::from(*h))
+        .max()
+        .unwrap_or(0)
+        .max(9);
+
+    grid::Grid::from_fn(trees.width(), trees.height(), |point| {
+        let level = 30 + (usize::from(trees[point]) * 225 / tallest) as u8;
+
+        match visible[point].is_visible() {
+            true => [level / 4, level, level / 4],
+            false => [level, level, level],
+        }
+    })
+}
+
+fn draw_scenic(forest: &Grid, policy: &ScenicPolicy) -> grid::Grid<Rgb> {
+    let scores = forest.scenic_score_map_with(policy);
+    let best = forest.best_scenic_spot_with(policy).map(|(point, _)| point);
+
+    // Scores grow multiplicatively, so a log scale shows more than a linear one.
+    // Adding one in f64 keeps scores capped at `usize::MAX` from overflowing.
+    let log = |score: usize| (score as f64 + 1.0).ln();
+    let max = scores.iter().map(|(_, score)| *score).max().unwrap_or(0);
+    let scale = log(max).max(f64::EPSILON);
+
+    grid::Grid::from_fn(scores.width(), scores.height(), |point| {
+        match Some(point) == best {
+            true => [255, 255, 255],
+            false => ramp(log(scores[point]) / scale),
+        }
+    })
+}
+
+/// The color at `t` (from 0 to 1) along [`SCENIC_RAMP`].
+fn ramp(t: f64) -> Rgb {
+    let position = t.clamp(0.0, 1.0) * (SCENIC_RAMP.len() - 1) as f64;
+    let idx = (position as usize).min(SCENIC_RAMP.len() - 2);
+    let (from, to) = (SCENIC_RAMP[idx], SCENIC_RAMP[idx + 1]);
+    let mix = position - idx as f64;
+
+    [0, 1, 2].map(|c| (from[c] as f64 + (to[c] as f64 - from[c] as f64) * mix).round() as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::grid::Point;
+
+    use super::{draw, ramp, Map};
+    use crate::grid::Grid;
+    use crate::scenic::ScenicPolicy;
+    use crate::TEST_GRID;
+
+    #[test]
+    fn it_finds_maps_by_name() {
+        assert_eq!(Some(Map::Visibility), Map::from_name("visibility"));
+        assert_eq!(Some(Map::Scenic), Map::from_name("scenic"));
+        assert_eq!(None, Map::from_name("scenic.png"));
+    }
+
+    #[test]
+    fn it_highlights_visible_trees() {
+        let forest = Grid::new(TEST_GRID).unwrap();
+        let image = draw(&forest, Map::Visibility, &ScenicPolicy::default());
+
+        // (1, 1) is a visible 5, (2, 2) a hidden 3.
+        assert_eq!([38, 155, 38], image[Point::new(1, 1)]);
+        assert_eq!([105, 105, 105], image[Point::new(2, 2)]);
+    }
+
+    #[test]
+    fn it_marks_the_best_scenic_spot() {
+        let forest = Grid::new(TEST_GRID).unwrap();
+        let image = draw(&forest, Map::Scenic, &ScenicPolicy::default());
+
+        assert_eq!([255, 255, 255], image[Point::new(2, 3)]);
+        assert_eq!([0, 0, 0], image[Point::new(0, 0)]);
+        assert_eq!([255, 255, 0], ramp(1.0));
+    }
+
+    #[test]
+    fn it_draws_saturated_scores_at_the_top_of_the_ramp() {
+        // Both 9s score more than a usize holds, so both are capped at usize::MAX.
+        let forest = Grid::new("00000\n09090\n00000").unwrap();
+        let policy =
+            ScenicPolicy::parse("product,n=1000000,e=1000000,s=1000000,w=1000000").unwrap();
+        let image = draw(&forest, Map::Scenic, &policy);
+
+        assert_eq!(
+            Some((Point::new(1, 1), usize::MAX)),
+            forest.best_scenic_spot_with(&policy)
+        );
+        assert_eq!([255, 255, 255], image[Point::new(1, 1)]);
+        assert_eq!([255, 255, 0], image[Point::new(3, 1)]);
+        assert_eq!([0, 0, 0], image[Point::new(0, 0)]);
+    }
+}
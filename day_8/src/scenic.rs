@@ -0,0 +1,217 @@
+use std::fmt;
+
+use shared::grid::Direction;
+
+/// How the viewing distances of a tree are combined into its scenic score.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Combine {
+    #[default]
+    Product,
+    Sum,
+    Min,
+    Max,
+}
+
+impl Combine {
+    fn name(self) -> &'static str {
+        match self {
+            Combine::Product => "product",
+            Combine::Sum => "sum",
+            Combine::Min => "min",
+            Combine::Max => "max",
+        }
+    }
+}
+
+/// A way of scoring how scenic a tree is. The puzzle multiplies the viewing
+/// distances to the four edges, which is the default policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScenicPolicy {
+    pub combine: Combine,
+    /// Whether the four diagonal directions are looked along too.
+    pub diagonals: bool,
+    /// What each viewing distance is multiplied by, in [`Direction::ALL`] order.
+    pub weights: [usize; 8],
+}
+
+impl Default for ScenicPolicy {
+    fn default() -> Self {
+        Self {
+            combine: Combine::Product,
+            diagonals: false,
+            weights: [1; 8],
+        }
+    }
+}
+
+/// The largest weight a direction can be given.
+pub const MAX_WEIGHT: usize = 1_000_000;
+
+/// Short names for the directions, as used for weights, in [`Direction::ALL`] order.
+const DIRECTION_NAMES: [&str; 8] = ["n", "ne", "e", "se", "s", "sw", "w", "nw"];
+
+impl ScenicPolicy {
+    /// Parse a policy like `product`, `sum,diagonals` or `min,n=2,s=2`: how
+    /// the distances are combined, optionally followed by `diagonals` and
+    /// weights for individual directions (`n`, `ne`, `e`, ... `nw`).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any part of the policy is not understood.
+    pub fn parse(spec: &str) -> shared::Result<Self> {
+        let invalid = |part: &str| {
+            shared::Error::invalid(format!(
+                "Invalid scenic policy `{}` in `{}`, expected e.g. `product` or `sum,diagonals,n=2`",
+                part, spec
+            ))
+        };
+
+        let mut parts = spec.split(',').map(str::trim);
+        let mut policy = ScenicPolicy {
+            combine: match parts.next().unwrap_or_default() {
+                "product" => Combine::Product,
+                "sum" => Combine::Sum,
+                "min" => Combine::Min,
+                "max" => Combine::Max,
+                part => return Err(invalid(part)),
+            },
+            ..Self::default()
+        };
+
+        for part in parts {
+            if part == "diagonals" {
+                policy.diagonals = true;
+                continue;
+            }
+
+            let (name, weight) = part.split_once('=').ok_or_else(|| invalid(part))?;
+            let idx = DIRECTION_NAMES
+                .iter()
+                .position(|n| *n == name.trim())
+                .ok_or_else(|| invalid(part))?;
+
+            policy.weights[idx] = weight
+                .trim()
+                .parse()
+                .ok()
+                .filter(|weight| *weight <= MAX_WEIGHT)
+                .ok_or_else(|| {
+                    shared::Error::invalid(format!(
+                        "Invalid weight in `{}`, expected a number from 0 to {}",
+                        part, MAX_WEIGHT
+                    ))
+                })?;
+        }
+
+        Ok(policy)
+    }
+
+    /// The directions a tree looks in.
+    pub fn directions(&self) -> impl Iterator<Item = Direction> {
+        let directions: &[Direction] = match self.diagonals {
+            true => &Direction::ALL,
+            false => &Direction::CARDINAL,
+        };
+
+        directions.iter().copied()
+    }
+
+    /// Combine the viewing distance in each direction into a score. Scores
+    /// too large for a `usize` stop at `usize::MAX`.
+    pub fn score(&self, distances: impl Iterator<Item = (Direction, usize)>) -> usize {
+        let weighted =
+            distances.map(|(direction, distance)| distance.saturating_mul(self.weight(direction)));
+
+        match self.combine {
+            Combine::Product => weighted.fold(1, usize::saturating_mul),
+            Combine::Sum => weighted.fold(0, usize::saturating_add),
+            Combine::Min => weighted.min().unwrap_or(0),
+            Combine::Max => weighted.max().unwrap_or(0),
+        }
+    }
+
+    fn weight(&self, direction: Direction) -> usize {
+        let idx = Direction::ALL.iter().position(|d| *d == direction);
+
+        idx.map_or(1, |idx| self.weights[idx])
+    }
+}
+
+impl fmt::Display for ScenicPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.combine.name())?;
+
+        if self.diagonals {
+            write!(f, ",diagonals")?;
+        }
+
+        for (name, weight) in DIRECTION_NAMES.iter().zip(self.weights) {
+            if weight != 1 {
+                write!(f, ",{}={}", name, weight)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::grid::Direction;
+
+    use super::{Combine, ScenicPolicy};
+
+    #[test]
+    fn it_parses_policies() {
+        let policy = ScenicPolicy::parse("sum, diagonals, n=2,sw=0").unwrap();
+
+        assert_eq!(Combine::Sum, policy.combine);
+        assert!(policy.diagonals);
+        assert_eq!([2, 1, 1, 1, 1, 0, 1, 1], policy.weights);
+        assert_eq!("sum,diagonals,n=2,sw=0", policy.to_string());
+        assert_eq!(
+            ScenicPolicy::default(),
+            ScenicPolicy::parse("product").unwrap()
+        );
+    }
+
+    #[test]
+    fn it_rejects_unknown_policies() {
+        assert!(ScenicPolicy::parse("average").is_err());
+        assert!(ScenicPolicy::parse("sum,up=2").is_err());
+        assert!(ScenicPolicy::parse("sum,n=x").is_err());
+        assert!(ScenicPolicy::parse("sum,everything").is_err());
+        assert!(ScenicPolicy::parse("product,n=18446744073709551615").is_err());
+        assert!(ScenicPolicy::parse("product,n=1000001").is_err());
+    }
+
+    #[test]
+    fn it_combines_weighted_distances() {
+        let distances = [
+            (Direction::North, 2),
+            (Direction::East, 2),
+            (Direction::South, 1),
+            (Direction::West, 2),
+        ];
+        let score = |spec| {
+            ScenicPolicy::parse(spec)
+                .unwrap()
+                .score(distances.into_iter())
+        };
+
+        assert_eq!(8, score("product"));
+        assert_eq!(7, score("sum"));
+        assert_eq!(1, score("min"));
+        assert_eq!(2, score("max"));
+        assert_eq!(24, score("product,s=3"));
+        assert_eq!(4, score("max,n=2"));
+    }
+
+    #[test]
+    fn it_caps_scores_that_do_not_fit() {
+        let policy = ScenicPolicy::parse("product,diagonals,n=1000000,s=1000000").unwrap();
+        let distances = Direction::ALL.map(|direction| (direction, 10_000));
+
+        assert_eq!(usize::MAX, policy.score(distances.into_iter()));
+    }
+}
@@ -0,0 +1,138 @@
+//! Drawing the forest in a terminal.
+
+use shared::grid::{self, Direction, Point};
+
+use crate::grid::{Grid, Height};
+use crate::scenic::ScenicPolicy;
+
+const RESET: &str = "\x1b[0m";
+const DIM: &str = "\x1b[2m";
+const VISIBLE: &str = "\x1b[1;32m";
+const SIGHT_LINE: &str = "\x1b[1;30;43m";
+const BEST_SPOT: &str = "\x1b[1;97;41m";
+
+/// What a tree is drawn as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mark {
+    Hidden,
+    Visible,
+    SightLine(Direction),
+    BestSpot,
+}
+
+/// Draw the forest one character per tree: hidden trees dimmed, visible
+/// trees bright and the trees seen from the best scenic spot under `policy`
+/// highlighted.
+///
+/// Without `color` the trees are drawn with plain ASCII markers instead:
+/// `.` for hidden trees, the height for visible trees (see [`symbol`]), `|`, `-`, `/` and `\`
+/// for the sight lines and `*` for the best spot.
+pub fn show(forest: &Grid, policy: &ScenicPolicy, color: bool) -> String {
+    let marks = marks(forest, policy);
+    let mut output = String::new();
+
+    for y in 0..forest.height() {
+        for x in 0..forest.width() {
+            let point = Point::new(x, y);
+            let height = forest.trees()[point];
+
+            match color {
+                true => output.push_str(&colored(marks[point], height)),
+                false => output.push(plain(marks[point], height)),
+            }
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
+
+fn marks(forest: &Grid, policy: &ScenicPolicy) -> grid::Grid<Mark> {
+    let mut marks = forest
+        .visibility_map()
+        .map(|sightlines| match sightlines.is_visible() {
+            true => Mark::Visible,
+            false => Mark::Hidden,
+        });
+
+    if let Some((best, _)) = forest.best_scenic_spot_with(policy) {
+        for direction in policy.directions() {
+            for point in forest.view(best, direction) {
+                marks[point] = Mark::SightLine(direction);
+            }
+        }
+
+        marks[best] = Mark::BestSpot;
+    }
+
+    marks
+}
+
+fn colored(mark: Mark, height: Height) -> String {
+    let style = match mark {
+        Mark::Hidden => DIM,
+        Mark::Visible => VISIBLE,
+        Mark::SightLine(_) => SIGHT_LINE,
+        Mark::BestSpot => BEST_SPOT,
+    };
+
+    format!("{}{}{}", style, symbol(height), RESET)
+}
+
+/// A tree's height as one character: `0` to `9`, then `a` to `z` for 10 to
+/// 35 and `#` for anything taller.
+fn symbol(height: Height) -> char {
+    char::from_digit(height.into(), 36).unwrap_or('#')
+}
+
+fn plain(mark: Mark, height: Height) -> char {
+    match mark {
+        Mark::Hidden => '.',
+        Mark::Visible => symbol(height),
+        Mark::SightLine(Direction::North | Direction::South) => '|',
+        Mark::SightLine(Direction::East | Direction::West) => '-',
+        Mark::SightLine(Direction::NorthEast | Direction::SouthWest) => '/',
+        Mark::SightLine(Direction::NorthWest | Direction::SouthEast) => '\\',
+        Mark::BestSpot => '*',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::show;
+    use crate::grid::Grid;
+    use crate::scenic::ScenicPolicy;
+    use crate::TEST_GRID;
+
+    #[test]
+    fn it_draws_the_forest_with_ascii_markers() {
+        let forest = Grid::new(TEST_GRID).unwrap();
+
+        assert_eq!(
+            "30373\n25|.2\n65|32\n--*--\n35|90\n",
+            show(&forest, &ScenicPolicy::default(), false)
+        );
+    }
+
+    #[test]
+    fn it_draws_diagonal_sight_lines() {
+        let forest = Grid::new(TEST_GRID).unwrap();
+        let policy = ScenicPolicy::parse("product,diagonals").unwrap();
+
+        assert_eq!(
+            "30373\n25|./\n6\\|/2\n--*--\n3/|\\0\n",
+            show(&forest, &policy, false)
+        );
+    }
+
+    #[test]
+    fn it_draws_the_forest_with_colors() {
+        let forest = Grid::new(TEST_GRID).unwrap();
+        let output = show(&forest, &ScenicPolicy::default(), true);
+
+        assert!(output.starts_with("\x1b[1;32m3\x1b[0m"));
+        assert!(output.contains("\x1b[1;97;41m5\x1b[0m"));
+        assert_eq!(25, output.matches("\x1b[0m").count());
+    }
+}
@@ -0,0 +1,328 @@
+//! A report on a forest beyond the two answers, to compare inputs.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use shared::grid::{Direction, Point};
+use shared::output::Format;
+
+use crate::grid::{Grid, Height, Sightlines};
+use crate::scenic::ScenicPolicy;
+
+/// How many of the best scenic spots are listed unless asked otherwise.
+pub const DEFAULT_TOP: usize = 5;
+
+/// The widest bar drawn in the text histograms.
+const BAR_WIDTH: usize = 40;
+
+/// Statistics about the trees of a forest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub trees: usize,
+    /// How many trees there are of each height found in the forest.
+    pub heights: BTreeMap<Height, usize>,
+    /// How many trees can be seen from outside the grid.
+    pub visible: usize,
+    /// How many trees can be seen from each edge, clockwise from north.
+    pub visible_from: [(Direction, usize); 4],
+    /// The best scenic spots with their scores, best first.
+    pub top_spots: Vec<(Point, usize)>,
+    /// How many trees score within each range of scenic scores: `0`, `1`,
+    /// `2..=3`, `4..=7` and so on, doubling each time.
+    pub scores: Vec<(usize, usize, usize)>,
+}
+
+impl Stats {
+    /// Collect the statistics of a forest, scoring trees under `policy` and
+    /// listing the `top` best scenic spots.
+    pub fn new(forest: &Grid, policy: &ScenicPolicy, top: usize) -> Self {
+        let mut heights = BTreeMap::new();
+        forest
+            .trees()
+            .iter()
+            .for_each(|(_, height)| *heights.entry(*height).or_default() += 1);
+
+        let visibility = forest.visibility_map();
+        let visible = forest.visible_count();
+        let visible_from = Direction::CARDINAL.map(|direction| {
+            let edge = Sightlines::from_direction(direction);
+            let count = visibility.iter().filter(|(_, s)| s.contains(edge)).count();
+
+            (direction, count)
+        });
+
+        let mut ranked: Vec<(Point, usize)> = forest
+            .scenic_score_map_with(policy)
+            .iter()
+            .map(|(point, score)| (point, *score))
+            .collect();
+        ranked.sort_by_key(|&(point, score)| (Reverse(score), point.y, point.x));
+
+        let mut scores: Vec<(usize, usize, usize)> = vec![];
+
+        for (_, score) in &ranked {
+            let (min, max) = bucket(*score);
+
+            match scores.iter_mut().find(|(low, _, _)| *low == min) {
+                Some((_, _, count)) => *count += 1,
+                None => scores.push((min, max, 1)),
+            }
+        }
+
+        scores.sort_unstable();
+        ranked.truncate(top);
+
+        Self {
+            trees: forest.width() * forest.height(),
+            heights,
+            visible,
+            visible_from,
+            top_spots: ranked,
+            scores,
+        }
+    }
+
+    /// The statistics in a runner output format. JSON is a single line,
+    /// `{"day":8,"stats":{...}}`, told apart from the answers by its `stats`
+    /// key. CSV has a row per value under a `stat,key,value` header of its own.
+    pub fn report(&self, format: Format) -> String {
+        match format {
+            Format::Text => self.to_string(),
+            Format::Json => format!("{}\n", self.to_json()),
+            Format::Csv => self.to_csv(),
+        }
+    }
+
+    /// The statistics as one line of JSON, tagged with the day.
+    pub fn to_json(&self) -> String {
+        let heights: Vec<String> = self
+            .heights
+            .iter()
+            .map(|(height, count)| format!("\"{}\":{}", height, count))
+            .collect();
+        let visible_from: Vec<String> = self
+            .visible_from
+            .iter()
+            .map(|(direction, count)| format!("\"{}\":{}", edge_name(*direction), count))
+            .collect();
+        let top_spots: Vec<String> = self
+            .top_spots
+            .iter()
+            .map(|(point, score)| {
+                format!(
+                    "{{\"x\":{},\"y\":{},\"score\":{}}}",
+                    point.x, point.y, score
+                )
+            })
+            .collect();
+        let scores: Vec<String> = self
+            .scores
+            .iter()
+            .map(|(min, max, trees)| {
+                format!("{{\"min\":{},\"max\":{},\"trees\":{}}}", min, max, trees)
+            })
+            .collect();
+
+        format!(
+            "{{\"day\":8,\"stats\":{{\"trees\":{},\"heights\":{{{}}},\"visible\":{},\"visible_from\":{{{}}},\"top_spots\":[{}],\"scenic_scores\":[{}]}}}}",
+            self.trees,
+            heights.join(","),
+            self.visible,
+            visible_from.join(","),
+            top_spots.join(","),
+            scores.join(",")
+        )
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("stat,key,value\n");
+
+        csv.push_str(&format!("trees,,{}\n", self.trees));
+
+        for (height, count) in &self.heights {
+            csv.push_str(&format!("height,{},{}\n", height, count));
+        }
+
+        csv.push_str(&format!("visible,,{}\n", self.visible));
+
+        for (direction, count) in &self.visible_from {
+            csv.push_str(&format!(
+                "visible_from,{},{}\n",
+                edge_name(*direction),
+                count
+            ));
+        }
+
+        for (point, score) in &self.top_spots {
+            csv.push_str(&format!("top_spot,{} {},{}\n", point.x, point.y, score));
+        }
+
+        for (min, max, trees) in &self.scores {
+            csv.push_str(&format!("scenic_scores,{}-{},{}\n", min, max, trees));
+        }
+
+        csv
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Tree heights:")?;
+        let tallest_bar = self.heights.values().max().copied().unwrap_or(0);
+
+        for (height, count) in &self.heights {
+            writeln!(
+                f,
+                "  {:>7}  {:>6}  {}",
+                height,
+                count,
+                bar(*count, tallest_bar)
+            )?;
+        }
+
+        writeln!(f, "Visible trees: {} of {}", self.visible, self.trees)?;
+
+        for (direction, count) in &self.visible_from {
+            writeln!(f, "  from the {:<5}  {}", edge_name(*direction), count)?;
+        }
+
+        writeln!(f, "Top scenic spots:")?;
+
+        for (rank, (point, score)) in self.top_spots.iter().enumerate() {
+            writeln!(
+                f,
+                "  {:>2}. ({}, {}) scoring {}",
+                rank + 1,
+                point.x,
+                point.y,
+                score
+            )?;
+        }
+
+        writeln!(f, "Scenic scores:")?;
+        let tallest_bar = self.scores.iter().map(|(_, _, n)| *n).max().unwrap_or(0);
+        let ranges: Vec<String> = self
+            .scores
+            .iter()
+            .map(|(min, max, _)| match min == max {
+                true => min.to_string(),
+                false => format!("{}-{}", min, max),
+            })
+            .collect();
+        let width = ranges.iter().map(String::len).max().unwrap_or(0).max(7);
+
+        for (range, (_, _, trees)) in ranges.iter().zip(&self.scores) {
+            writeln!(
+                f,
+                "  {:>width$}  {:>6}  {}",
+                range,
+                trees,
+                bar(*trees, tallest_bar)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The range of scores a score is counted in: `0`, `1`, `2..=3`, `4..=7`...
+fn bucket(score: usize) -> (usize, usize) {
+    match score {
+        0 => (0, 0),
+        _ => {
+            let min = 1 << score.ilog2();
+
+            (min, min + (min - 1))
+        }
+    }
+}
+
+fn bar(count: usize, most: usize) -> String {
+    match most {
+        0 => String::new(),
+        _ => "#".repeat((count * BAR_WIDTH).div_ceil(most)),
+    }
+}
+
+fn edge_name(direction: Direction) -> &'static str {
+    match direction {
+        Direction::North => "north",
+        Direction::East => "east",
+        Direction::South => "south",
+        _ => "west",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use shared::grid::{Direction, Point};
+
+    use super::{bucket, Stats};
+    use crate::grid::Grid;
+    use crate::scenic::ScenicPolicy;
+    use crate::TEST_GRID;
+
+    #[test]
+    fn it_collects_forest_statistics() {
+        let forest = Grid::new(TEST_GRID).unwrap();
+        let stats = Stats::new(&forest, &ScenicPolicy::default(), 3);
+
+        assert_eq!(25, stats.trees);
+        assert_eq!(
+            vec![2, 1, 3, 9, 1, 5, 1, 1, 2],
+            stats.heights.values().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(None, stats.heights.get(&8));
+        assert_eq!(21, stats.visible);
+        assert_eq!(
+            [
+                (Direction::North, 10),
+                (Direction::East, 11),
+                (Direction::South, 8),
+                (Direction::West, 11)
+            ],
+            stats.visible_from
+        );
+        assert_eq!(
+            vec![
+                (Point::new(2, 3), 8),
+                (Point::new(1, 2), 6),
+                (Point::new(2, 1), 4)
+            ],
+            stats.top_spots
+        );
+        assert_eq!(
+            25,
+            stats
+                .scores
+                .iter()
+                .map(|(_, _, trees)| trees)
+                .sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn it_buckets_scores_by_powers_of_two() {
+        assert_eq!((0, 0), bucket(0));
+        assert_eq!((1, 1), bucket(1));
+        assert_eq!((2, 3), bucket(3));
+        assert_eq!((8, 15), bucket(8));
+    }
+
+    #[test]
+    fn it_writes_json_on_one_tagged_line() {
+        let forest = Grid::new("12\n34").unwrap();
+        let stats = Stats::new(&forest, &ScenicPolicy::default(), 1);
+
+        assert_eq!(
+            concat!(
+                r#"{"day":8,"stats":{"trees":4,"heights":{"1":1,"2":1,"3":1,"4":1},"visible":4,"#,
+                r#""visible_from":{"north":4,"east":2,"south":2,"west":4},"#,
+                r#""top_spots":[{"x":0,"y":0,"score":0}],"#,
+                r#""scenic_scores":[{"min":0,"max":0,"trees":4}]}}"#
+            ),
+            stats.to_json()
+        );
+    }
+}
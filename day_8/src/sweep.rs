@@ -0,0 +1,77 @@
+//! Sweeps along a single line of trees, shared by [`Grid`](crate::grid::Grid),
+//! [`Volume`](crate::volume::Volume) and the parallel solver.
+//!
+//! A line is given as the indices of its trees in a slice of heights, in the
+//! order they are visited, so the same sweep works for rows, columns and the
+//! axes of any number of dimensions.
+
+use crate::grid::Height;
+
+/// Call `visible` with every tree seen from the start of a line, tracking
+/// the tallest tree so far.
+pub(crate) fn mark_visible(
+    heights: &[Height],
+    line: impl Iterator<Item = usize>,
+    mut visible: impl FnMut(usize),
+) {
+    let mut tallest: Option<Height> = None;
+
+    for idx in line {
+        if tallest.is_none_or(|tallest| heights[idx] > tallest) {
+            visible(idx);
+            tallest = Some(heights[idx]);
+        }
+    }
+}
+
+/// Call `distance` with how far every tree on a line sees back towards the
+/// start of the line, keeping a stack of the trees that could still block
+/// the view.
+pub(crate) fn viewing_distances(
+    heights: &[Height],
+    line: impl Iterator<Item = usize>,
+    mut distance: impl FnMut(usize, usize),
+) {
+    // Steps along the line and heights of earlier trees, strictly shrinking towards the top.
+    let mut blockers: Vec<(usize, Height)> = vec![];
+
+    for (step, idx) in line.enumerate() {
+        let height = heights[idx];
+
+        while blockers
+            .last()
+            .is_some_and(|&(_, blocker)| blocker < height)
+        {
+            blockers.pop();
+        }
+
+        distance(
+            idx,
+            match blockers.last() {
+                Some(&(blocker_step, _)) => step - blocker_step,
+                None => step,
+            },
+        );
+
+        blockers.push((step, height));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mark_visible, viewing_distances};
+
+    #[test]
+    fn it_sweeps_a_line_in_either_direction() {
+        let heights = [3, 0, 3, 7, 3];
+        let mut visible = vec![];
+        let mut distances = [0; 5];
+
+        mark_visible(&heights, 0..5, |idx| visible.push(idx));
+        mark_visible(&heights, (0..5).rev(), |idx| visible.push(idx));
+        viewing_distances(&heights, 0..5, |idx, d| distances[idx] = d);
+
+        assert_eq!(vec![0, 3, 4, 3], visible);
+        assert_eq!([0, 1, 2, 3, 1], distances);
+    }
+}
@@ -0,0 +1,408 @@
+//! Forests with any number of dimensions, like stacked layers of terrain.
+//!
+//! Trees look along every axis in both directions, so in `N` dimensions a
+//! tree has `2·N` views instead of the four of a [`Grid`]. A two
+//! dimensional volume gives the same answers as the grid it was made from.
+
+use shared::random::Rng;
+
+use crate::grid::{Grid, Height};
+use crate::sweep;
+
+/// The position of a tree, one coordinate per axis. For two dimensions this
+/// is `[x, y]`, for three `[x, y, layer]`.
+pub type Coord<const N: usize> = [usize; N];
+
+/// One of the `2·N` directions: along an axis, towards higher coordinates or lower ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AxisDirection {
+    pub axis: usize,
+    pub forward: bool,
+}
+
+/// Tree heights on an `N` dimensional box, stored with the first axis changing fastest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Volume<const N: usize> {
+    dims: [usize; N],
+    heights: Vec<Height>,
+}
+
+impl<const N: usize> Volume<N> {
+    /// A volume of `dims` trees along each axis.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    ///   - Any axis is empty
+    ///   - The number of heights does not match the dimensions
+    pub fn new(dims: [usize; N], heights: Vec<Height>) -> shared::Result<Self> {
+        let size = dims
+            .iter()
+            .try_fold(1_usize, |size, dim| size.checked_mul(*dim));
+
+        if dims.contains(&0) || size != Some(heights.len()) {
+            return Err(shared::Error::invalid(format!(
+                "A {:?} forest cannot hold {} trees",
+                dims,
+                heights.len()
+            )));
+        }
+
+        Ok(Self { dims, heights })
+    }
+
+    /// A volume of random tree heights. The same seed always grows the same forest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any axis is empty.
+    pub fn random(dims: [usize; N], seed: u64) -> Self {
+        let mut rng = Rng::new(seed);
+        let heights = (0..dims.iter().product())
+            .map(|_| rng.below(10) as Height)
+            .collect();
+
+        Self::new(dims, heights).expect("A forest needs at least one tree along every axis")
+    }
+
+    pub fn dims(&self) -> [usize; N] {
+        self.dims
+    }
+
+    pub fn height(&self, coord: Coord<N>) -> Height {
+        self.heights[self.index(coord)]
+    }
+
+    /// All `2·N` directions, for each axis first forward then backward.
+    pub fn directions() -> impl Iterator<Item = AxisDirection> {
+        (0..N).flat_map(|axis| {
+            [true, false]
+                .into_iter()
+                .map(move |forward| AxisDirection { axis, forward })
+        })
+    }
+
+    /// Whether a tree can be seen from outside the volume along any axis.
+    pub fn is_visible(&self, coord: Coord<N>) -> bool {
+        let height = self.height(coord);
+
+        Self::directions().any(|direction| {
+            self.ray(coord, direction)
+                .all(|idx| self.heights[idx] < height)
+        })
+    }
+
+    /// How many trees can be seen looking from a tree in a direction.
+    pub fn sight_distance(&self, coord: Coord<N>, direction: AxisDirection) -> usize {
+        let height = self.height(coord);
+        let mut distance = 0;
+
+        for idx in self.ray(coord, direction) {
+            distance += 1;
+
+            if self.heights[idx] >= height {
+                break;
+            }
+        }
+
+        distance
+    }
+
+    /// How far a tree can see in every direction, multiplied together.
+    pub fn scenic_score(&self, coord: Coord<N>) -> usize {
+        Self::directions()
+            .map(|direction| self.sight_distance(coord, direction))
+            .product()
+    }
+
+    /// How many trees can be seen from outside the volume, found by sweeping
+    /// every line of trees from both ends in O(number of trees × N) time.
+    pub fn visible_count(&self) -> usize {
+        let mut visible = vec![false; self.heights.len()];
+
+        for axis in 0..N {
+            for line in self.lines(axis) {
+                sweep::mark_visible(&self.heights, line.iter().copied(), |idx| {
+                    visible[idx] = true
+                });
+                sweep::mark_visible(&self.heights, line.iter().rev().copied(), |idx| {
+                    visible[idx] = true
+                });
+            }
+        }
+
+        visible.iter().filter(|visible| **visible).count()
+    }
+
+    /// The tree with the highest scenic score, with that score. Every line of
+    /// trees is swept from both ends with a stack of the trees that could
+    /// still block the view. Ties go to the tree with the lowest coordinates,
+    /// comparing the last axis first.
+    pub fn best_scenic_spot(&self) -> Option<(Coord<N>, usize)> {
+        let mut scores = vec![1; self.heights.len()];
+
+        for axis in 0..N {
+            for line in self.lines(axis) {
+                let mut apply = |idx: usize, distance| {
+                    scores[idx] = usize::saturating_mul(scores[idx], distance)
+                };
+
+                sweep::viewing_distances(&self.heights, line.iter().copied(), &mut apply);
+                sweep::viewing_distances(&self.heights, line.iter().rev().copied(), &mut apply);
+            }
+        }
+
+        scores
+            .iter()
+            .enumerate()
+            .max_by_key(|&(idx, score)| (*score, std::cmp::Reverse(idx)))
+            .map(|(idx, score)| (self.coord(idx), *score))
+    }
+
+    /// Every line of trees along an axis, as indices into the heights.
+    fn lines(&self, axis: usize) -> impl Iterator<Item = Vec<usize>> + '_ {
+        let stride = self.stride(axis);
+
+        (0..self.heights.len())
+            .filter(move |idx| self.coord(*idx)[axis] == 0)
+            .map(move |start| {
+                (0..self.dims[axis])
+                    .map(|step| start + step * stride)
+                    .collect()
+            })
+    }
+
+    /// The indices of the trees met walking from a tree to the edge, not
+    /// including the tree itself.
+    fn ray(&self, coord: Coord<N>, direction: AxisDirection) -> impl Iterator<Item = usize> {
+        let start = self.index(coord);
+        let stride = self.stride(direction.axis);
+        let position = coord[direction.axis];
+
+        let steps = match direction.forward {
+            true => self.dims[direction.axis] - position - 1,
+            false => position,
+        };
+
+        (1..=steps).map(move |step| match direction.forward {
+            true => start + step * stride,
+            false => start - step * stride,
+        })
+    }
+
+    fn stride(&self, axis: usize) -> usize {
+        self.dims[..axis].iter().product()
+    }
+
+    fn index(&self, coord: Coord<N>) -> usize {
+        assert!(
+            coord.iter().zip(self.dims).all(|(c, dim)| *c < dim),
+            "{:?} is outside of a {:?} forest",
+            coord,
+            self.dims
+        );
+
+        (0..N).map(|axis| coord[axis] * self.stride(axis)).sum()
+    }
+
+    fn coord(&self, mut idx: usize) -> Coord<N> {
+        let mut coord = [0; N];
+
+        for (axis, dim) in self.dims.iter().enumerate() {
+            coord[axis] = idx % dim;
+            idx /= dim;
+        }
+
+        coord
+    }
+}
+
+impl From<&Grid> for Volume<2> {
+    fn from(grid: &Grid) -> Self {
+        Self {
+            dims: [grid.width(), grid.height()],
+            heights: grid.trees().cells().to_vec(),
+        }
+    }
+}
+
+impl Volume<3> {
+    /// Parse layers of single digit tree heights, each laid out like a day 8
+    /// grid and separated by blank lines.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    ///   - A layer is not a valid grid
+    ///   - A layer is not the same size as the first one
+    pub fn parse_layers(input: &str) -> shared::Result<Self> {
+        let mut dims = None;
+        let mut heights = vec![];
+        let mut layers = 0;
+
+        let lines: Vec<&str> = input.lines().collect();
+        let mut start = 0;
+
+        while start < lines.len() {
+            if lines[start].trim().is_empty() {
+                start += 1;
+                continue;
+            }
+
+            let end = lines[start..]
+                .iter()
+                .position(|line| line.trim().is_empty())
+                .map_or(lines.len(), |len| start + len);
+
+            let layer =
+                Grid::new(&lines[start..end].join("\n")).map_err(|e| offset_line(e, start))?;
+            let [width, height] = *dims.get_or_insert([layer.width(), layer.height()]);
+
+            if [width, height] != [layer.width(), layer.height()] {
+                return Err(shared::Error::parse(
+                    start + 1,
+                    1,
+                    lines[start],
+                    format!(
+                        "expected a layer of {}x{} trees like the first one, found {}x{}",
+                        width,
+                        height,
+                        layer.width(),
+                        layer.height()
+                    ),
+                ));
+            }
+
+            heights.extend_from_slice(layer.trees().cells());
+            layers += 1;
+            start = end;
+        }
+
+        let [width, height] = dims.unwrap_or([0, 0]);
+
+        Self::new([width, height, layers], heights)
+    }
+}
+
+/// Move a parse error in a layer to its line in the whole input.
+fn offset_line(error: shared::Error, offset: usize) -> shared::Error {
+    match error {
+        shared::Error::Parse {
+            line,
+            column,
+            snippet,
+            message,
+        } => shared::Error::parse(line + offset, column, &snippet, message),
+        e => e,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AxisDirection, Volume};
+    use crate::grid::Grid;
+    use crate::TEST_GRID;
+
+    #[test]
+    fn it_answers_the_same_as_a_grid_in_two_dimensions() {
+        let grid = Grid::new(TEST_GRID).unwrap();
+        let volume = Volume::from(&grid);
+
+        assert_eq!(21, volume.visible_count());
+        assert_eq!(Some(([2, 3], 8)), volume.best_scenic_spot());
+
+        for seed in 0..200 {
+            let grid = Grid::random(1 + seed % 9, 1 + seed / 20, seed as u64);
+            let volume = Volume::from(&grid);
+
+            for (point, _) in grid.trees().iter() {
+                let coord = [point.x, point.y];
+
+                assert_eq!(grid.is_visible(point.x, point.y), volume.is_visible(coord));
+                assert_eq!(
+                    grid.scenic_score(point.x, point.y),
+                    volume.scenic_score(coord)
+                );
+            }
+
+            let visible = grid.visible_count();
+            let best = grid
+                .best_scenic_spot()
+                .map(|(point, score)| ([point.x, point.y], score));
+
+            assert_eq!(visible, volume.visible_count());
+            assert_eq!(best, volume.best_scenic_spot());
+        }
+    }
+
+    #[test]
+    fn it_looks_along_every_axis_in_three_dimensions() {
+        // A tall tree in the middle of a 3x3x3 cube of short trees.
+        let mut heights = vec![1; 27];
+        heights[13] = 5;
+        let volume = Volume::new([3, 3, 3], heights).unwrap();
+
+        assert_eq!(6, Volume::<3>::directions().count());
+        assert!(volume.is_visible([1, 1, 1]));
+        assert_eq!(1, volume.scenic_score([1, 1, 1]));
+        assert_eq!(27, volume.visible_count());
+
+        let up = AxisDirection {
+            axis: 2,
+            forward: true,
+        };
+        assert_eq!(1, volume.sight_distance([1, 1, 1], up));
+        assert_eq!(0, volume.sight_distance([1, 1, 2], up));
+    }
+
+    #[test]
+    fn it_agrees_with_brute_force_in_three_dimensions() {
+        for seed in 0..100 {
+            let dims = [1 + seed % 4, 1 + seed % 3, 1 + seed % 5];
+            let volume = Volume::random(dims, seed as u64);
+
+            let mut visible = 0;
+            let mut best: Option<([usize; 3], usize)> = None;
+
+            for z in 0..dims[2] {
+                for y in 0..dims[1] {
+                    for x in 0..dims[0] {
+                        let score = volume.scenic_score([x, y, z]);
+
+                        visible += volume.is_visible([x, y, z]) as usize;
+
+                        if best.is_none_or(|(_, best)| score > best) {
+                            best = Some(([x, y, z], score));
+                        }
+                    }
+                }
+            }
+
+            assert_eq!(visible, volume.visible_count(), "{:?}", dims);
+            assert_eq!(best, volume.best_scenic_spot(), "{:?}", dims);
+        }
+    }
+
+    #[test]
+    fn it_parses_layers() {
+        let volume = Volume::parse_layers("123\n456\n\n789\n012\n").unwrap();
+
+        assert_eq!([3, 2, 2], volume.dims());
+        assert_eq!(6, volume.height([2, 1, 0]));
+        assert_eq!(0, volume.height([0, 1, 1]));
+
+        let mismatch = Volume::parse_layers("123\n456\n\n78\n01\n").unwrap_err();
+        assert_eq!(Some((4, 1)), mismatch.position());
+        assert!(mismatch
+            .to_string()
+            .contains("expected a layer of 3x2 trees like the first one, found 2x2"));
+
+        assert!(Volume::parse_layers("").is_err());
+        assert_eq!(
+            Some((5, 2)),
+            Volume::parse_layers("12\n34\n\n56\n7x\n")
+                .err()
+                .and_then(|e| e.position())
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::{fmt, fs, io};
+
+use crate::{Answer, Error, Part, Result};
+
+/// The name of the file holding expected answers, next to a day's input.
+pub const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+/// Expected answers loaded from an `answers.toml` file like:
+///
+/// ```toml
+/// [day_7]
+/// part1 = 1297683
+/// part2 = 5756764
+/// ```
+#[derive(Debug, Default)]
+pub struct ExpectedAnswers {
+    answers: HashMap<(u8, Part), String>,
+}
+
+impl ExpectedAnswers {
+    /// Load the answers file sitting next to the given input file.
+    /// A missing file yields no expected answers.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    ///   - The file exists but we are unable to read it
+    ///   - The file is not a valid answers file
+    pub fn for_input(input_path: &Path) -> Result<Self> {
+        Self::load(&answers_path(input_path))
+    }
+
+    /// Load an answers file. A missing file yields no expected answers.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    ///   - The file exists but we are unable to read it
+    ///   - The file is not a valid answers file
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(io::Error::new(
+                e.kind(),
+                format!("Unable to read `{}`: {}", path.display(), e),
+            )
+            .into()),
+        }
+    }
+
+    /// Parse the contents of an answers file. Only the subset of TOML needed
+    /// here is understood: `[day_N]` tables holding `part1` and `part2` keys
+    /// with integer or string values, plus comments and blank lines.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a line cannot be understood.
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut answers = HashMap::new();
+        let mut day: Option<u8> = None;
+
+        for (idx, raw_line) in content.lines().enumerate() {
+            let invalid = |message: &str| {
+                let column = raw_line.len() - raw_line.trim_start().len() + 1;
+                Error::parse(idx + 1, column, raw_line, message)
+            };
+            let line = strip_comment(raw_line).trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let number = header.trim().trim_start_matches("day_").parse().ok();
+                day = Some(number.ok_or_else(|| invalid("expected a `[day_N]` table"))?);
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| invalid("expected `key = value`"))?;
+
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                key => return Err(invalid(&format!("unknown key `{}`", key))),
+            };
+
+            let day = day.ok_or_else(|| invalid("answer outside of a `[day_N]` table"))?;
+            let value = parse_value(value.trim()).ok_or_else(|| invalid("invalid value"))?;
+
+            answers.insert((day, part), value);
+        }
+
+        Ok(Self { answers })
+    }
+
+    /// The expected answer for a part, if one is known.
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Compare an answer against the expected one.
+    pub fn verify(&self, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// The path of the answers file for an input file.
+pub fn answers_path(input_path: &Path) -> PathBuf {
+    input_path.with_file_name(ANSWERS_FILE_NAME)
+}
+
+/// Whether an answer matched the expected one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+
+    for (idx, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..idx],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+/// Parse a TOML integer (`1_000` style allowed) or basic string.
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(text) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        return Some(text.to_string());
+    }
+
+    let number: i128 = value.replace('_', "").parse().ok()?;
+    Some(number.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExpectedAnswers, Verdict};
+    use crate::{Answer, Part};
+
+    const ANSWERS: &str = "\
+# Known answers
+[day_5]
+part1 = \"CMZ\" # top crates
+part2 = \"MCD\"
+
+[day_7]
+part1 = 95_437
+";
+
+    #[test]
+    fn it_parses_answers_by_day_and_part() {
+        let answers = ExpectedAnswers::parse(ANSWERS).unwrap();
+
+        assert_eq!(Some("CMZ"), answers.get(5, Part::One));
+        assert_eq!(Some("MCD"), answers.get(5, Part::Two));
+        assert_eq!(Some("95437"), answers.get(7, Part::One));
+        assert_eq!(None, answers.get(7, Part::Two));
+    }
+
+    #[test]
+    fn it_verifies_answers() {
+        let answers = ExpectedAnswers::parse(ANSWERS).unwrap();
+
+        assert_eq!(
+            Verdict::Pass,
+            answers.verify(7, Part::One, &Answer::from(95437))
+        );
+        assert_eq!(
+            Verdict::Fail,
+            answers.verify(5, Part::One, &Answer::from("MCD"))
+        );
+        assert_eq!(
+            Verdict::Unknown,
+            answers.verify(7, Part::Two, &Answer::from(1))
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_lines() {
+        assert!(ExpectedAnswers::parse("part1 = 1").is_err());
+        assert!(ExpectedAnswers::parse("[day_1]\npart3 = 1").is_err());
+        assert!(ExpectedAnswers::parse("[day_1]\npart1 = \"open").is_err());
+        assert!(ExpectedAnswers::parse("[first]").is_err());
+    }
+}
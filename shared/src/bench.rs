@@ -0,0 +1,147 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::{Error, Part, Result, Solver};
+
+/// The number of times each day is run when benchmarking, unless overridden.
+pub const DEFAULT_RUNS: usize = 10;
+
+/// A separately timed step of solving a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// The spread of timings for a phase over all benchmark runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarise a set of timings. Returns `None` when there are none.
+    pub fn from_timings(timings: &[Duration]) -> Option<Self> {
+        let mut sorted = timings.to_vec();
+        sorted.sort();
+
+        let median = match sorted.len() {
+            0 => return None,
+            len if len % 2 == 0 => (sorted[len / 2 - 1] + sorted[len / 2]) / 2,
+            len => sorted[len / 2],
+        };
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        })
+    }
+}
+
+/// Benchmark results for a single day.
+pub struct Report {
+    pub day: u8,
+    pub runs: usize,
+    pub phases: Vec<(Phase, Stats)>,
+}
+
+/// Run a day `runs` times, timing parsing and each of the given parts.
+///
+/// # Errors
+///
+/// This function will return an error if:
+///   - `runs` is zero
+///   - Parsing or solving any part fails
+pub fn run(solver: &dyn Solver, input: &str, parts: &[Part], runs: usize) -> Result<Report> {
+    if runs == 0 {
+        return Err(Error::invalid("Benchmarks need at least one run"));
+    }
+
+    let mut timings: Vec<(Phase, Vec<Duration>)> = vec![];
+
+    for _ in 0..runs {
+        for (phase, elapsed) in solver.time_phases(input, parts)? {
+            match timings.iter_mut().find(|(p, _)| *p == phase) {
+                Some((_, phase_timings)) => phase_timings.push(elapsed),
+                None => timings.push((phase, vec![elapsed])),
+            }
+        }
+    }
+
+    let phases = timings
+        .into_iter()
+        .filter_map(|(phase, phase_timings)| {
+            Stats::from_timings(&phase_timings).map(|stats| (phase, stats))
+        })
+        .collect();
+
+    Ok(Report {
+        day: solver.day(),
+        runs,
+        phases,
+    })
+}
+
+/// Print a table with the min/median/max timing of each phase.
+pub fn print_report(report: &Report) {
+    println!("Day {} ({} runs)", report.day, report.runs);
+    println!(
+        "{:<6} | {:>10} | {:>10} | {:>10}",
+        "Phase", "Min", "Median", "Max"
+    );
+    println!("-------+------------+------------+-----------");
+
+    for (phase, stats) in &report.phases {
+        println!(
+            "{:<6} | {:>10} | {:>10} | {:>10}",
+            phase.to_string(),
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.max)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn it_summarises_an_odd_number_of_timings() {
+        let stats = Stats::from_timings(&millis(&[5, 1, 9])).unwrap();
+
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(5), stats.median);
+        assert_eq!(Duration::from_millis(9), stats.max);
+    }
+
+    #[test]
+    fn it_averages_the_middle_timings_for_the_median() {
+        let stats = Stats::from_timings(&millis(&[4, 1, 8, 2])).unwrap();
+
+        assert_eq!(Duration::from_millis(3), stats.median);
+    }
+
+    #[test]
+    fn it_has_no_stats_without_timings() {
+        assert_eq!(None, Stats::from_timings(&[]));
+    }
+}
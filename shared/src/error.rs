@@ -0,0 +1,136 @@
+use std::{error, fmt, io};
+
+/// Everything that can go wrong while reading, parsing or solving a puzzle.
+#[derive(Debug)]
+pub enum Error {
+    /// Reading the input (or another file) failed.
+    Io(io::Error),
+
+    /// The input is malformed. `line` and `column` are 1-based and `snippet`
+    /// holds the full offending line, so the error can point right at it.
+    Parse {
+        line: usize,
+        column: usize,
+        snippet: String,
+        message: String,
+    },
+
+    /// Something other than the puzzle input is wrong, like a command line argument.
+    Invalid(String),
+
+    /// The input was understood but has no answer.
+    Unsolvable(String),
+}
+
+impl Error {
+    /// A parse error at a 1-based line and column of the input.
+    pub fn parse(line: usize, column: usize, snippet: &str, message: impl Into<String>) -> Self {
+        Error::Parse {
+            line,
+            column,
+            snippet: snippet.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// The 1-based line and column a parse error points at, or `None` for
+    /// any other error.
+    pub fn position(&self) -> Option<(usize, usize)> {
+        match self {
+            Error::Parse { line, column, .. } => Some((*line, *column)),
+            _ => None,
+        }
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Error::Invalid(message.into())
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        Error::Unsolvable(message.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Parse {
+                line,
+                column,
+                snippet,
+                message,
+            } => {
+                let gutter = " ".repeat(line.to_string().len());
+                let caret_offset = " ".repeat(column.saturating_sub(1));
+
+                writeln!(f, "line {}, column {}: {}", line, column, message)?;
+                writeln!(f, "{} |", gutter)?;
+                writeln!(f, "{} | {}", line, snippet)?;
+                write!(f, "{} | {}^", gutter, caret_offset)
+            }
+            Error::Invalid(message) => write!(f, "{}", message),
+            Error::Unsolvable(message) => write!(f, "no solution: {}", message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+
+    #[test]
+    fn it_finds_the_position_of_parse_errors() {
+        assert_eq!(Some((3, 2)), Error::parse(3, 2, "1x", "bad").position());
+        assert_eq!(None, Error::invalid("bad").position());
+    }
+
+    #[test]
+    fn it_points_at_the_offending_column() {
+        let error = Error::parse(3, 3, "12a4", "expected a number");
+
+        assert_eq!(
+            "line 3, column 3: expected a number\n  |\n3 | 12a4\n  |   ^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn it_widens_the_gutter_for_long_line_numbers() {
+        let error = Error::parse(120, 1, "move x from 1 to 2", "expected a number");
+
+        assert_eq!(
+            "line 120, column 1: expected a number\n    |\n120 | move x from 1 to 2\n    | ^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn it_renders_other_errors_as_their_message() {
+        assert_eq!(
+            "Invalid day `x`",
+            Error::invalid("Invalid day `x`").to_string()
+        );
+        assert_eq!(
+            "no solution: no marker found",
+            Error::unsolvable("no marker found").to_string()
+        );
+    }
+}
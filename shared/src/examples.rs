@@ -0,0 +1,237 @@
+//! Checks a day's solver against the examples from the puzzle statement.
+//!
+//! Examples live in `day_N/examples/`: every `<name>.txt` input is paired
+//! with a `<name>.toml` answers file in the same format as `answers.toml`.
+//! Each day's tests only need [`example_tests!`](crate::example_tests).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::answers::{ExpectedAnswers, Verdict};
+use crate::{runner, Error, Input, Part, Result, Solver};
+
+/// A single example input and the answers it should produce.
+pub struct Example {
+    pub name: String,
+    pub input: PathBuf,
+    pub expected: ExpectedAnswers,
+}
+
+/// Find every example in a directory, sorted by name.
+///
+/// # Errors
+///
+/// This function will return an error if:
+///   - We are unable to read the directory
+///   - An example's answers file is missing or invalid
+pub fn discover(dir: &Path) -> Result<Vec<Example>> {
+    let mut inputs = vec![];
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            inputs.push(path);
+        }
+    }
+
+    inputs.sort();
+    inputs.into_iter().map(load).collect()
+}
+
+/// Load the example called `name` from a directory.
+///
+/// # Errors
+///
+/// This function will return an error if the example's answers file is missing or invalid.
+pub fn find(dir: &Path, name: &str) -> Result<Example> {
+    load(dir.join(format!("{}.txt", name)))
+}
+
+fn load(input: PathBuf) -> Result<Example> {
+    let answers = input.with_extension("toml");
+
+    if !answers.is_file() {
+        return Err(Error::invalid(format!(
+            "Example `{}` has no `{}`",
+            input.display(),
+            answers.display()
+        )));
+    }
+
+    Ok(Example {
+        name: file_stem(&input),
+        expected: ExpectedAnswers::load(&answers)?,
+        input,
+    })
+}
+
+/// Solve an example, returning a description of everything that went wrong.
+pub fn check(solver: &dyn Solver, example: &Example) -> Vec<String> {
+    let day = solver.day();
+
+    if Part::ALL
+        .iter()
+        .all(|part| example.expected.get(day, *part).is_none())
+    {
+        return vec![format!("no expected answers for day {}", day)];
+    }
+
+    let results = Input::Path(example.input.clone())
+        .read()
+        .and_then(|input| runner::run_parts(solver, &input, &Part::ALL, &example.expected));
+
+    let results = match results {
+        Ok(results) => results,
+        Err(e) => return vec![e.to_string()],
+    };
+
+    results
+        .into_iter()
+        .filter_map(|result| {
+            let expected = example.expected.get(day, result.part);
+
+            match (result.answer, result.verdict) {
+                (Err(e), _) => Some(format!("part {}: {}", result.part, e)),
+                (Ok(answer), Verdict::Fail) => Some(format!(
+                    "part {}: expected {}, got {}",
+                    result.part,
+                    expected.unwrap_or_default(),
+                    answer
+                )),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Check a single example, panicking with everything that went wrong.
+/// Meant to be called from a test, see [`example_tests!`](crate::example_tests).
+pub fn assert_example(solver: &dyn Solver, dir: &Path, name: &str) {
+    let example = match find(dir, name) {
+        Ok(example) => example,
+        Err(e) => panic!("Unable to load example `{}`: {}", name, e),
+    };
+
+    let problems = check(solver, &example);
+
+    assert!(
+        problems.is_empty(),
+        "day {} example {} failed:\n{}",
+        solver.day(),
+        name,
+        problems.join("\n")
+    );
+}
+
+/// Check that every example in a directory is in `names`, so none is left
+/// without a test.
+pub fn assert_all_listed(dir: &Path, names: &[&str]) {
+    let examples = match discover(dir) {
+        Ok(examples) => examples,
+        Err(e) => panic!("Unable to load examples from `{}`: {}", dir.display(), e),
+    };
+
+    let missing: Vec<&str> = examples
+        .iter()
+        .map(|example| example.name.as_str())
+        .filter(|name| !names.contains(name))
+        .collect();
+
+    assert!(
+        missing.is_empty(),
+        "Examples in `{}` without a test: {}",
+        dir.display(),
+        missing.join(", ")
+    );
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Define a test for each named example in the calling crate's `examples/`
+/// directory, named after the example, plus one making sure no example is
+/// left out.
+///
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     shared::example_tests!(super::Day6, example_1, example_2);
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solver:expr, $($name:ident),+ $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                let dir = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+
+                $crate::examples::assert_example(&$solver, &dir, stringify!($name));
+            }
+        )+
+
+        #[test]
+        fn every_example_has_a_test() {
+            let dir = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+
+            $crate::examples::assert_all_listed(&dir, &[$(stringify!($name)),+]);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{check, discover};
+    use crate::{Answer, Result, Solution};
+
+    struct Doubler;
+
+    impl Solution for Doubler {
+        const DAY: u8 = 1;
+
+        type Parsed = i64;
+
+        fn parse(input: &str) -> Result<i64> {
+            crate::parse::Cursor::new(1, input.trim_end()).complete(|c| c.number())
+        }
+
+        fn part1(number: &i64) -> Result<Answer> {
+            Ok((number * 2).into())
+        }
+
+        fn part2(number: &i64) -> Result<Answer> {
+            Ok((number * 3).into())
+        }
+    }
+
+    #[test]
+    fn it_reports_mismatched_and_missing_answers() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.txt"), "2\n").unwrap();
+        fs::write(dir.join("a.toml"), "[day_1]\npart1 = 4\npart2 = 5\n").unwrap();
+        fs::write(dir.join("b.txt"), "3\n").unwrap();
+        fs::write(dir.join("b.toml"), "# nothing yet\n").unwrap();
+        fs::write(dir.join("notes.md"), "ignored").unwrap();
+
+        let examples = discover(&dir).unwrap();
+        let problems: Vec<Vec<String>> = examples.iter().map(|e| check(&Doubler, e)).collect();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            vec!["a", "b"],
+            examples.iter().map(|e| &e.name).collect::<Vec<_>>()
+        );
+        assert_eq!(vec!["part 2: expected 5, got 6".to_string()], problems[0]);
+        assert_eq!(
+            vec!["no expected answers for day 1".to_string()],
+            problems[1]
+        );
+    }
+}
@@ -0,0 +1,455 @@
+//! A rectangular grid of cells, as found in many puzzles.
+
+use std::ops::{Index, IndexMut};
+
+use crate::{Error, Result};
+
+/// The position of a cell. `x` grows to the east and `y` to the south, so
+/// `(0, 0)` is the top left corner.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// The neighboring point in a direction, unless that would leave the
+    /// top or left of the grid.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        let (dx, dy) = direction.offset();
+
+        self.offset(dx, dy)
+    }
+
+    /// The point `dx` columns east and `dy` rows south of this one, unless
+    /// that would leave the top or left of the grid.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self { x, y }
+    }
+}
+
+/// One of the eight directions to move in from a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four directions sharing an edge with a cell, clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The change in `(x, y)` for a single step.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::NorthEast => Direction::SouthWest,
+            Direction::East => Direction::West,
+            Direction::SouthEast => Direction::NorthWest,
+            Direction::South => Direction::North,
+            Direction::SouthWest => Direction::NorthEast,
+            Direction::West => Direction::East,
+            Direction::NorthWest => Direction::SouthEast,
+        }
+    }
+}
+
+/// A `width` by `height` grid, stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid built from its cells, row by row.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if there are not `width * height` cells.
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        if Some(cells.len()) != width.checked_mul(height) {
+            return Err(Error::invalid(format!(
+                "A {}x{} grid needs {} cells, got {}",
+                width,
+                height,
+                width.saturating_mul(height),
+                cells.len()
+            )));
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// A grid with every cell set by calling `cell` with its position.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(&mut cell)
+            .collect();
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parse a character map, one row per line, turning every character
+    /// into a cell with `cell`. `expected` describes a valid cell for errors.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    ///   - The map is empty
+    ///   - `cell` rejects a character
+    ///   - A row is not as wide as the first one
+    pub fn from_chars(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (idx, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+
+            for (column, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or_else(|| {
+                    Error::parse(idx + 1, column + 1, line, format!("expected {}", expected))
+                })?;
+
+                cells.push(value);
+                row_width += 1;
+            }
+
+            let width = *width.get_or_insert(row_width);
+
+            if row_width != width {
+                return Err(Error::parse(
+                    idx + 1,
+                    width.min(row_width) + 1,
+                    line,
+                    format!("expected a row of {} cells", width),
+                ));
+            }
+
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Self::from_cells(width, height, cells),
+            _ => Err(Error::parse(1, 1, "", "expected a row of cells")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether a point lies within the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        match self.contains(point) {
+            true => self.cells.get(point.y * self.width + point.x),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        match self.contains(point) {
+            true => self.cells.get_mut(point.y * self.width + point.x),
+            false => None,
+        }
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Every cell, row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// The rows of the grid, from north to south.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of a single column, from north to south.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let start = if x < self.width { x } else { self.cells.len() };
+
+        self.cells.iter().skip(start).step_by(self.width.max(1))
+    }
+
+    /// The columns of the grid, from west to east.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The cells met walking from `from` in a straight line until the edge
+    /// of the grid, not including `from` itself.
+    pub fn ray(&self, from: Point, direction: Direction) -> Ray<'_, T> {
+        self.line(from, direction.offset())
+    }
+
+    /// Like [`Grid::ray`], but taking steps of `(dx, dy)` so any slope can be
+    /// followed, like two columns east for every row north with `(2, -1)`.
+    /// A step of `(0, 0)` meets no cells.
+    pub fn line(&self, from: Point, step: (isize, isize)) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            point: Some(from).filter(|_| step != (0, 0)),
+            step,
+        }
+    }
+
+    /// The (up to) four cells sharing an edge with a point.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbors(point, &Direction::CARDINAL)
+    }
+
+    /// The (up to) eight cells sharing an edge or a corner with a point.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbors(point, &Direction::ALL)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        point: Point,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Point, &'a T)> {
+        directions.iter().filter_map(move |direction| {
+            let neighbor = point.step(*direction)?;
+
+            self.get(neighbor).map(|cell| (neighbor, cell))
+        })
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside of a {}x{} grid",
+                point, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        match self.get_mut(point) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside of a {}x{} grid", point, width, height),
+        }
+    }
+}
+
+/// The cells in a straight line from a point, see [`Grid::ray`] and [`Grid::line`].
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    point: Option<Point>,
+    step: (isize, isize),
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (Point, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.point?.offset(self.step.0, self.step.1);
+        let cell = next.and_then(|point| self.grid.get(point));
+
+        self.point = cell.and(next);
+
+        Some((next?, cell?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Grid, Point};
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::from_chars(input, "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn it_parses_character_maps() {
+        let grid = digits("123\n456");
+
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&6), grid.get(Point::new(2, 1)));
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!(4, grid[Point::new(0, 1)]);
+    }
+
+    #[test]
+    fn it_rejects_malformed_character_maps() {
+        let parse = |input| Grid::from_chars(input, "a digit", |c| c.to_digit(10));
+
+        assert!(parse("").is_err());
+        assert!(parse("12\n3x").is_err());
+        assert!(parse("12\n3").is_err());
+        assert!(parse("12\n345").is_err());
+    }
+
+    #[test]
+    fn it_iterates_rows_and_columns() {
+        let grid = digits("123\n456");
+
+        let rows: Vec<&[u32]> = grid.rows().collect();
+        let columns: Vec<Vec<u32>> = grid.columns().map(|c| c.copied().collect()).collect();
+
+        assert_eq!(vec![&[1, 2, 3][..], &[4, 5, 6][..]], rows);
+        assert_eq!(vec![vec![1, 4], vec![2, 5], vec![3, 6]], columns);
+        assert_eq!(0, grid.column(3).count());
+    }
+
+    #[test]
+    fn it_casts_rays_until_the_edge() {
+        let grid = digits("123\n456\n789");
+        let ray = |x, y, direction| -> Vec<u32> {
+            grid.ray(Point::new(x, y), direction)
+                .map(|(_, cell)| *cell)
+                .collect()
+        };
+
+        assert_eq!(vec![2], ray(1, 1, Direction::North));
+        assert_eq!(vec![2, 3], ray(0, 0, Direction::East));
+        assert_eq!(vec![5, 1], ray(2, 2, Direction::NorthWest));
+        assert_eq!(vec![5, 7], ray(2, 0, Direction::SouthWest));
+        assert!(ray(0, 0, Direction::West).is_empty());
+    }
+
+    #[test]
+    fn it_follows_lines_of_any_slope() {
+        let grid = digits("1234\n5678\n9012");
+        let line = |x, y, step| -> Vec<u32> {
+            grid.line(Point::new(x, y), step)
+                .map(|(_, cell)| *cell)
+                .collect()
+        };
+
+        assert_eq!(vec![7], line(0, 0, (2, 1)));
+        assert_eq!(vec![6, 3], line(0, 2, (1, -1)));
+        assert_eq!(vec![6], line(3, 2, (-2, -1)));
+        assert!(line(1, 1, (0, 0)).is_empty());
+    }
+
+    #[test]
+    fn it_finds_neighbors() {
+        let grid = digits("123\n456\n789");
+        let neighbors = |point, eight: bool| -> Vec<u32> {
+            match eight {
+                true => grid.neighbors8(point).map(|(_, c)| *c).collect(),
+                false => grid.neighbors4(point).map(|(_, c)| *c).collect(),
+            }
+        };
+
+        assert_eq!(vec![2, 6, 8, 4], neighbors(Point::new(1, 1), false));
+        assert_eq!(8, neighbors(Point::new(1, 1), true).len());
+        assert_eq!(vec![2, 5, 4], neighbors(Point::new(0, 0), true));
+    }
+
+    #[test]
+    fn it_builds_grids_from_cells_and_functions() {
+        let grid = Grid::from_fn(3, 2, |p| p.x + p.y * 10);
+
+        assert_eq!(12, grid[Point::new(2, 1)]);
+        assert_eq!(
+            grid,
+            Grid::from_cells(3, 2, vec![0, 1, 2, 10, 11, 12]).unwrap()
+        );
+        assert!(Grid::from_cells(3, 2, vec![0; 5]).is_err());
+        assert_eq!(Some(&true), grid.map(|c| *c > 10).get(Point::new(1, 1)));
+    }
+}
@@ -0,0 +1,170 @@
+//! Writing grids of colors as PPM or PNG images, to look at puzzle state.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use flate2::write::ZlibEncoder;
+use flate2::{Compression, Crc};
+
+use crate::grid::{Grid, Point};
+use crate::{Error, Result};
+
+/// A color as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// The eight bytes every PNG file starts with.
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Blow every pixel up into a `factor` by `factor` square, so small grids
+/// are easier to see.
+pub fn scale(image: &Grid<Rgb>, factor: usize) -> Grid<Rgb> {
+    let factor = factor.max(1);
+
+    Grid::from_fn(image.width() * factor, image.height() * factor, |point| {
+        image[Point::new(point.x / factor, point.y / factor)]
+    })
+}
+
+/// Encode an image as a binary PPM (`P6`).
+pub fn encode_ppm(image: &Grid<Rgb>) -> Vec<u8> {
+    let mut data = format!("P6\n{} {}\n255\n", image.width(), image.height()).into_bytes();
+    data.extend(image.iter().flat_map(|(_, pixel)| *pixel));
+
+    data
+}
+
+/// Encode an image as an 8-bit RGB PNG.
+///
+/// # Errors
+///
+/// This function will return an error if the image is too large for a PNG,
+/// or compressing the pixels fails.
+pub fn encode_png(image: &Grid<Rgb>) -> Result<Vec<u8>> {
+    let too_large = || Error::invalid("The image is too large for a PNG");
+    let width = u32::try_from(image.width()).map_err(|_| too_large())?;
+    let height = u32::try_from(image.height()).map_err(|_| too_large())?;
+
+    let mut header = vec![];
+    header.extend(width.to_be_bytes());
+    header.extend(height.to_be_bytes());
+    // Bit depth 8, truecolor, default compression, filter and no interlacing.
+    header.extend([8, 2, 0, 0, 0]);
+
+    // Every row starts with its filter type, which is always "none" here.
+    let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+
+    for row in image.rows() {
+        encoder.write_all(&[0])?;
+        encoder.write_all(&row.concat())?;
+    }
+
+    let mut png = PNG_SIGNATURE.to_vec();
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &encoder.finish()?);
+    write_chunk(&mut png, b"IEND", &[]);
+
+    Ok(png)
+}
+
+/// Write an image to a file, as a PNG or PPM depending on its extension.
+///
+/// # Errors
+///
+/// This function will return an error if:
+///   - The extension is not `png` or `ppm`
+///   - Encoding the image or writing the file fails
+pub fn save(image: &Grid<Rgb>, path: &Path) -> Result<()> {
+    let data = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("png") => encode_png(image)?,
+        Some("ppm") => encode_ppm(image),
+        _ => {
+            return Err(Error::invalid(format!(
+                "Unable to write `{}`, expected a .png or .ppm file",
+                path.display()
+            )))
+        }
+    };
+
+    fs::write(path, data).map_err(|e| {
+        Error::Io(io::Error::new(
+            e.kind(),
+            format!("Unable to write `{}`: {}", path.display(), e),
+        ))
+    })
+}
+
+/// Append a PNG chunk: its length, type, data and a CRC of the type and data.
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    let mut crc = Crc::new();
+    crc.update(kind);
+    crc.update(data);
+
+    png.extend((data.len() as u32).to_be_bytes());
+    png.extend(kind);
+    png.extend(data);
+    png.extend(crc.sum().to_be_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::ZlibDecoder;
+
+    use super::{encode_png, encode_ppm, scale, Rgb};
+    use crate::grid::{Grid, Point};
+
+    fn checkerboard() -> Grid<Rgb> {
+        Grid::from_fn(2, 3, |p| match (p.x + p.y) % 2 {
+            0 => [255, 0, 0],
+            _ => [0, 0, 255],
+        })
+    }
+
+    #[test]
+    fn it_encodes_ppm_images() {
+        let ppm = encode_ppm(&checkerboard());
+
+        assert!(ppm.starts_with(b"P6\n2 3\n255\n"));
+        assert_eq!(b"P6\n2 3\n255\n".len() + 2 * 3 * 3, ppm.len());
+        assert_eq!(
+            &[255, 0, 0, 0, 0, 255],
+            &ppm[ppm.len() - 18..ppm.len() - 12]
+        );
+    }
+
+    #[test]
+    fn it_encodes_png_images() {
+        let png = encode_png(&checkerboard()).unwrap();
+
+        // Signature, then the header chunk with the size of the image.
+        assert_eq!(b"\x89PNG\r\n\x1a\n", &png[..8]);
+        assert_eq!(b"IHDR", &png[12..16]);
+        assert_eq!([0, 0, 0, 2, 0, 0, 0, 3, 8, 2, 0, 0, 0], png[16..29]);
+
+        // Every PNG ends with the same empty IEND chunk, CRC included.
+        assert_eq!(
+            [0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82],
+            png[png.len() - 12..]
+        );
+
+        let idat_length = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+        let mut pixels = vec![];
+        ZlibDecoder::new(&png[41..41 + idat_length])
+            .read_to_end(&mut pixels)
+            .unwrap();
+
+        assert_eq!(3 * (1 + 2 * 3), pixels.len());
+        assert_eq!([0, 255, 0, 0, 0, 0, 255], pixels[..7]);
+    }
+
+    #[test]
+    fn it_scales_images_up() {
+        let scaled = scale(&checkerboard(), 3);
+
+        assert_eq!((6, 9), (scaled.width(), scaled.height()));
+        assert_eq!([255, 0, 0], scaled[Point::new(2, 2)]);
+        assert_eq!([0, 0, 255], scaled[Point::new(3, 2)]);
+    }
+}
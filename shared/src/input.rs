@@ -0,0 +1,161 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use flate2::read::GzDecoder;
+
+use crate::answers::ExpectedAnswers;
+use crate::Result;
+
+/// The first two bytes of every gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Stdin,
+    Path(PathBuf),
+}
+
+impl Input {
+    /// The input file used when none is supplied, relative to the workspace root.
+    pub fn default_for(day: u8) -> Self {
+        Input::Path(PathBuf::from(format!("day_{}/input.txt", day)))
+    }
+
+    /// Pick the input for a day from an optional command line argument:
+    ///   - `-` reads from stdin
+    ///   - any other value is a path to a file
+    ///   - with no argument, stdin is used if it is a pipe, otherwise the day's
+    ///     default input. Scripts with stdin closed or redirected from
+    ///     `/dev/null` still get the default input.
+    pub fn resolve(day: u8, arg: Option<&str>) -> Self {
+        match arg {
+            Some("-") => Input::Stdin,
+            Some(path) => Input::Path(PathBuf::from(path)),
+            None if stdin_is_pipe() => Input::Stdin,
+            None => Self::default_for(day),
+        }
+    }
+
+    /// Read the whole input, transparently decompressing gzip data.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    ///   - We are unable to read the file or stdin
+    ///   - The data is gzip compressed but cannot be decompressed
+    ///   - The input is not valid UTF-8
+    pub fn read(&self) -> Result<String> {
+        let bytes = match self {
+            Input::Stdin => {
+                let mut bytes = vec![];
+                io::stdin().read_to_end(&mut bytes).map(|_| bytes)
+            }
+            Input::Path(path) => fs::read(path),
+        };
+
+        let text = bytes
+            .and_then(decode)
+            .map_err(|e| io::Error::new(e.kind(), format!("Unable to read {}: {}", self, e)))?;
+
+        Ok(text)
+    }
+
+    /// The expected answers for this input, from the `answers.toml` next to it.
+    /// Input read from stdin has no expected answers.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the answers file exists but
+    /// cannot be read.
+    pub fn expected_answers(&self) -> Result<ExpectedAnswers> {
+        match self {
+            Input::Stdin => Ok(ExpectedAnswers::default()),
+            Input::Path(path) => ExpectedAnswers::for_input(path),
+        }
+    }
+}
+
+/// Whether stdin is a pipe, like `generate | day_8`.
+#[cfg(unix)]
+fn stdin_is_pipe() -> bool {
+    use std::os::fd::AsFd;
+    use std::os::unix::fs::FileTypeExt;
+
+    io::stdin()
+        .as_fd()
+        .try_clone_to_owned()
+        .and_then(|fd| fs::File::from(fd).metadata())
+        .is_ok_and(|metadata| metadata.file_type().is_fifo())
+}
+
+/// Without a way to tell pipes apart, stdin is only read when asked for with `-`.
+#[cfg(not(unix))]
+fn stdin_is_pipe() -> bool {
+    false
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Input::Stdin => write!(f, "stdin"),
+            Input::Path(path) => write!(f, "`{}`", path.display()),
+        }
+    }
+}
+
+/// Turn raw input bytes into text, decompressing them first if they are gzipped.
+fn decode(bytes: Vec<u8>) -> io::Result<String> {
+    let bytes = if bytes.starts_with(&GZIP_MAGIC) {
+        let mut decompressed = vec![];
+        GzDecoder::new(bytes.as_slice()).read_to_end(&mut decompressed)?;
+        decompressed
+    } else {
+        bytes
+    };
+
+    String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    use super::{decode, Input};
+
+    #[test]
+    fn it_reads_plain_text_as_is() {
+        assert_eq!(
+            "30373\n25512\n",
+            decode(b"30373\n25512\n".to_vec()).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_decompresses_gzip_input() {
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(b"1000\n2000\n\n3000\n").unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        assert_eq!("1000\n2000\n\n3000\n", decode(compressed).unwrap());
+    }
+
+    #[test]
+    fn it_rejects_input_that_is_not_utf8() {
+        assert!(decode(vec![0xff, 0xfe, 0x00]).is_err());
+    }
+
+    #[test]
+    fn it_resolves_dash_to_stdin() {
+        assert_eq!(Input::Stdin, Input::resolve(7, Some("-")));
+        assert_eq!(
+            Input::Path("day_7/other.txt".into()),
+            Input::resolve(7, Some("day_7/other.txt"))
+        );
+    }
+}
@@ -0,0 +1,246 @@
+//! Machine-readable output for the runner, as JSON lines or CSV.
+//!
+//! JSON output has one object per line, so it can be streamed into other
+//! tools. Both formats give timings in whole microseconds.
+
+use std::fmt;
+
+use crate::bench::Report;
+use crate::runner::{self, PartResult};
+use crate::{bench, Answer};
+
+/// How the runner prints its results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// Parse a format name as given on the command line.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+            Format::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+const RESULTS_HEADER: &str = "day,part,answer,verdict,elapsed_us,error";
+const REPORTS_HEADER: &str = "day,runs,phase,min_us,median_us,max_us";
+
+/// Print the answer for each part in the given format.
+pub fn print_results(results: &[PartResult], format: Format) {
+    match format {
+        Format::Text => runner::print_answers(results),
+        Format::Json => results
+            .iter()
+            .for_each(|result| println!("{}", result_to_json(result))),
+        Format::Csv => {
+            println!("{}", RESULTS_HEADER);
+            results
+                .iter()
+                .for_each(|result| println!("{}", result_to_csv(result)));
+        }
+    }
+}
+
+/// Print benchmark reports in the given format.
+pub fn print_reports(reports: &[Report], format: Format) {
+    match format {
+        Format::Text => reports.iter().for_each(|report| {
+            bench::print_report(report);
+            println!();
+        }),
+        Format::Json => reports
+            .iter()
+            .flat_map(report_to_json)
+            .for_each(|line| println!("{}", line)),
+        Format::Csv => {
+            println!("{}", REPORTS_HEADER);
+            reports
+                .iter()
+                .flat_map(report_to_csv)
+                .for_each(|row| println!("{}", row));
+        }
+    }
+}
+
+fn result_to_json(result: &PartResult) -> String {
+    let (answer, error) = match &result.answer {
+        Ok(Answer::Number(number)) => (number.to_string(), "null".to_string()),
+        Ok(Answer::Text(text)) => (json_string(text), "null".to_string()),
+        Err(e) => ("null".to_string(), json_string(&e.to_string())),
+    };
+
+    format!(
+        "{{\"day\":{},\"part\":{},\"answer\":{},\"verdict\":{},\"elapsed_us\":{},\"error\":{}}}",
+        result.day,
+        result.part,
+        answer,
+        json_string(&result.verdict.to_string()),
+        result.elapsed.as_micros(),
+        error
+    )
+}
+
+fn result_to_csv(result: &PartResult) -> String {
+    let (answer, error) = match &result.answer {
+        Ok(answer) => (answer.to_string(), String::new()),
+        Err(e) => (String::new(), e.to_string()),
+    };
+
+    format!(
+        "{},{},{},{},{},{}",
+        result.day,
+        result.part,
+        csv_field(&answer),
+        result.verdict,
+        result.elapsed.as_micros(),
+        csv_field(&error)
+    )
+}
+
+fn report_to_json(report: &Report) -> Vec<String> {
+    report
+        .phases
+        .iter()
+        .map(|(phase, stats)| {
+            format!(
+                "{{\"day\":{},\"runs\":{},\"phase\":{},\"min_us\":{},\"median_us\":{},\"max_us\":{}}}",
+                report.day,
+                report.runs,
+                json_string(&phase.to_string()),
+                stats.min.as_micros(),
+                stats.median.as_micros(),
+                stats.max.as_micros()
+            )
+        })
+        .collect()
+}
+
+fn report_to_csv(report: &Report) -> Vec<String> {
+    report
+        .phases
+        .iter()
+        .map(|(phase, stats)| {
+            format!(
+                "{},{},{},{},{},{}",
+                report.day,
+                report.runs,
+                csv_field(&phase.to_string()),
+                stats.min.as_micros(),
+                stats.median.as_micros(),
+                stats.max.as_micros()
+            )
+        })
+        .collect()
+}
+
+/// A quoted JSON string, with quotes, backslashes and control characters escaped.
+fn json_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+/// A CSV field, quoted only when it contains a separator, quote or line break.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{csv_field, json_string, result_to_csv, result_to_json};
+    use crate::answers::Verdict;
+    use crate::runner::PartResult;
+    use crate::{Answer, Error, Part};
+
+    fn result(answer: crate::Result<Answer>, verdict: Verdict) -> PartResult {
+        PartResult {
+            day: 5,
+            part: Part::One,
+            answer,
+            verdict,
+            elapsed: Duration::from_micros(1234),
+        }
+    }
+
+    #[test]
+    fn it_renders_results_as_json() {
+        let text = result(Ok("CMZ".into()), Verdict::Pass);
+        let number = result(Ok(24000.into()), Verdict::Unknown);
+
+        assert_eq!(
+            r#"{"day":5,"part":1,"answer":"CMZ","verdict":"PASS","elapsed_us":1234,"error":null}"#,
+            result_to_json(&text)
+        );
+        assert_eq!(
+            r#"{"day":5,"part":1,"answer":24000,"verdict":"UNKNOWN","elapsed_us":1234,"error":null}"#,
+            result_to_json(&number)
+        );
+    }
+
+    #[test]
+    fn it_renders_errors_as_json() {
+        let failed = result(Err(Error::unsolvable("no \"marker\"")), Verdict::Unknown);
+
+        assert_eq!(
+            r#"{"day":5,"part":1,"answer":null,"verdict":"UNKNOWN","elapsed_us":1234,"error":"no solution: no \"marker\""}"#,
+            result_to_json(&failed)
+        );
+    }
+
+    #[test]
+    fn it_renders_results_as_csv() {
+        let text = result(Ok("CMZ".into()), Verdict::Fail);
+        let failed = result(Err(Error::unsolvable("a, b")), Verdict::Unknown);
+
+        assert_eq!("5,1,CMZ,FAIL,1234,", result_to_csv(&text));
+        assert_eq!(
+            "5,1,,UNKNOWN,1234,\"no solution: a, b\"",
+            result_to_csv(&failed)
+        );
+    }
+
+    #[test]
+    fn it_escapes_special_characters() {
+        assert_eq!(r#""a\\b\n\u0001""#, json_string("a\\b\n\u{1}"));
+        assert_eq!("\"say \"\"hi\"\"\"", csv_field("say \"hi\""));
+        assert_eq!("plain", csv_field("plain"));
+    }
+}
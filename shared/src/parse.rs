@@ -0,0 +1,371 @@
+//! Small building blocks for parsing puzzle input.
+//!
+//! Input is split into lines (or blank-line separated blocks of lines), and
+//! each line is consumed left to right through a [`Cursor`]. Every failure
+//! is an [`Error::Parse`] pointing at the position the cursor reached.
+
+use std::str::FromStr;
+
+use crate::{Error, Result};
+
+/// A position within a single line of input, from which values are parsed
+/// left to right. A cursor may be limited to part of its line, like a
+/// single fixed-width column, but errors always show the whole line.
+#[derive(Debug, Clone, Copy)]
+pub struct Cursor<'a> {
+    line_number: usize,
+    line: &'a str,
+    pos: usize,
+    end: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// A cursor at the start of a line. `line_number` is 1-based.
+    pub fn new(line_number: usize, line: &'a str) -> Self {
+        Self {
+            line_number,
+            line,
+            pos: 0,
+            end: line.len(),
+        }
+    }
+
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    /// The whole line this cursor is on.
+    pub fn line(&self) -> &'a str {
+        self.line
+    }
+
+    /// The text that has not been consumed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.pos..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.end
+    }
+
+    /// The 1-based column of the cursor within its line.
+    pub fn column(&self) -> usize {
+        self.line[..self.pos].chars().count() + 1
+    }
+
+    /// A parse error pointing at the current position.
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        Error::parse(self.line_number, self.column(), self.line, message)
+    }
+
+    /// Run `parser` and make sure it consumed everything.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `parser` fails or leaves input behind.
+    pub fn complete<T>(mut self, parser: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        let value = parser(&mut self)?;
+        self.end()?;
+
+        Ok(value)
+    }
+
+    /// Expect that everything has been consumed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if there is input left.
+    pub fn end(&self) -> Result<()> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(self.error("unexpected trailing input")),
+        }
+    }
+
+    /// Consume `literal` if the remaining input starts with it.
+    pub fn eat(&mut self, literal: &str) -> bool {
+        if self.rest().starts_with(literal) {
+            self.pos += literal.len();
+            return true;
+        }
+
+        false
+    }
+
+    /// Consume `literal`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the remaining input does not start with `literal`.
+    pub fn literal(&mut self, literal: &str) -> Result<()> {
+        match self.eat(literal) {
+            true => Ok(()),
+            false => Err(self.error(format!("expected `{}`", literal))),
+        }
+    }
+
+    /// Consume a single character.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if there is no input left.
+    pub fn next_char(&mut self) -> Result<char> {
+        match self.rest().chars().next() {
+            Some(c) => {
+                self.pos += c.len_utf8();
+                Ok(c)
+            }
+            None => Err(self.error("expected a character")),
+        }
+    }
+
+    /// Consume characters for as long as they match `predicate`.
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.pos += len;
+
+        &rest[..len]
+    }
+
+    /// Consume everything up to `delimiter` (or the end of the input) as a
+    /// cursor of its own, then skip past the delimiter.
+    pub fn take_until(&mut self, delimiter: &str) -> Cursor<'a> {
+        let len = self.rest().find(delimiter).unwrap_or(self.rest().len());
+        let taken = Cursor {
+            end: self.pos + len,
+            ..*self
+        };
+
+        self.pos = (taken.end + delimiter.len()).min(self.end);
+        taken
+    }
+
+    /// Consume the remaining input as a cursor of its own.
+    pub fn take_rest(&mut self) -> Cursor<'a> {
+        let taken = *self;
+        self.pos = self.end;
+
+        taken
+    }
+
+    /// Consume a decimal number, with a leading `-` for negative numbers.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if there is no number or it does not fit in `T`.
+    pub fn number<T: FromStr>(&mut self) -> Result<T> {
+        let start = *self;
+        let negative = self.eat("-");
+        let digits = self.take_while(|c| c.is_ascii_digit());
+
+        if digits.is_empty() {
+            *self = start;
+            return Err(self.error("expected a number"));
+        }
+
+        let text = &start.rest()[..digits.len() + negative as usize];
+
+        text.parse()
+            .map_err(|_| start.error(format!("`{}` is out of range", text)))
+    }
+
+    /// Consume one or more items separated by `separator`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any item fails to parse.
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = vec![item(self)?];
+
+        while self.eat(separator) {
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Split a `key<separator>value` line into cursors over the (trimmed)
+    /// key and value, consuming the whole input.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the separator is missing.
+    pub fn key_value(&mut self, separator: &str) -> Result<(Cursor<'a>, Cursor<'a>)> {
+        if !self.rest().contains(separator) {
+            let end = Cursor {
+                pos: self.end,
+                ..*self
+            };
+            return Err(end.error(format!("expected `{}`", separator)));
+        }
+
+        let key = self.take_until(separator).trimmed();
+        let value = self.take_rest().trimmed();
+
+        Ok((key, value))
+    }
+
+    /// Split the remaining input into fixed-width columns, the last one
+    /// possibly narrower than the others.
+    pub fn columns(&self, width: usize) -> Vec<Cursor<'a>> {
+        let boundaries: Vec<usize> = self
+            .rest()
+            .char_indices()
+            .map(|(idx, _)| self.pos + idx)
+            .step_by(width.max(1))
+            .collect();
+
+        boundaries
+            .iter()
+            .enumerate()
+            .map(|(idx, &pos)| Cursor {
+                pos,
+                end: boundaries.get(idx + 1).copied().unwrap_or(self.end),
+                ..*self
+            })
+            .collect()
+    }
+
+    /// The same cursor without leading or trailing whitespace.
+    pub fn trimmed(&self) -> Cursor<'a> {
+        let rest = self.rest();
+        let pos = self.pos + (rest.len() - rest.trim_start().len());
+        let end = self.end - (rest.len() - rest.trim_end().len());
+
+        Cursor {
+            pos,
+            end: end.max(pos),
+            ..*self
+        }
+    }
+}
+
+/// A cursor for every line of the input.
+pub fn cursors(input: &str) -> impl Iterator<Item = Cursor<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| Cursor::new(idx + 1, line))
+}
+
+/// Parse every line of the input with `parser`, which must consume the whole line.
+///
+/// # Errors
+///
+/// This function will return an error for the first line that fails to parse.
+pub fn lines<T>(input: &str, mut parser: impl FnMut(&mut Cursor) -> Result<T>) -> Result<Vec<T>> {
+    cursors(input)
+        .map(|cursor| cursor.complete(&mut parser))
+        .collect()
+}
+
+/// Group the lines of the input into blocks separated by blank lines.
+pub fn blocks(input: &str) -> Vec<Vec<Cursor<'_>>> {
+    let mut blocks = vec![vec![]];
+
+    for cursor in cursors(input) {
+        if cursor.rest().trim().is_empty() {
+            blocks.push(vec![]);
+        } else if let Some(block) = blocks.last_mut() {
+            block.push(cursor);
+        }
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blocks, lines, Cursor};
+
+    #[test]
+    fn it_parses_numbers_and_literals() {
+        let pairs = lines("2-4,6-8\n12-13,-1-5", |c| {
+            let a: i32 = c.number()?;
+            c.literal("-")?;
+            let b: i32 = c.number()?;
+            c.literal(",")?;
+            let d: i32 = c.number()?;
+            c.literal("-")?;
+            let e: i32 = c.number()?;
+
+            Ok((a, b, d, e))
+        })
+        .unwrap();
+
+        assert_eq!(vec![(2, 4, 6, 8), (12, 13, -1, 5)], pairs);
+    }
+
+    #[test]
+    fn it_points_at_the_failing_position() {
+        let error = lines("move 1 from 2 to 3\nmove x from 2 to 3", |c| {
+            c.literal("move ")?;
+            c.number::<u32>()?;
+            c.take_rest();
+            Ok(())
+        })
+        .unwrap_err();
+
+        assert_eq!(Some((2, 6)), error.position());
+    }
+
+    #[test]
+    fn it_rejects_trailing_input_and_out_of_range_numbers() {
+        let trailing = lines("12 ", |c| c.number::<u8>()).unwrap_err();
+        let too_big = lines("256", |c| c.number::<u8>()).unwrap_err();
+
+        assert_eq!(Some((1, 3)), trailing.position());
+        assert_eq!(Some((1, 1)), too_big.position());
+    }
+
+    #[test]
+    fn it_parses_separated_lists() {
+        let numbers = Cursor::new(1, "1, 20, 300")
+            .complete(|c| c.separated(", ", |c| c.number::<u32>()))
+            .unwrap();
+
+        assert_eq!(vec![1, 20, 300], numbers);
+    }
+
+    #[test]
+    fn it_groups_lines_into_blocks() {
+        let blocks = blocks("1000\n2000\n\n4000\n\n5000\n6000\n");
+        let sizes: Vec<usize> = blocks.iter().map(Vec::len).collect();
+
+        assert_eq!(vec![2, 1, 2], sizes);
+        assert_eq!(4, blocks[1][0].line_number());
+    }
+
+    #[test]
+    fn it_splits_fixed_width_columns() {
+        let columns = Cursor::new(1, "    [D]    ").columns(4);
+        let texts: Vec<&str> = columns.iter().map(|c| c.rest()).collect();
+
+        assert_eq!(vec!["    ", "[D] ", "   "], texts);
+        assert_eq!(5, columns[1].column());
+    }
+
+    #[test]
+    fn it_splits_key_value_lines() {
+        let (key, mut value) = Cursor::new(1, "part1 = 24000").key_value("=").unwrap();
+
+        assert_eq!("part1", key.rest());
+        assert_eq!(24000, value.number::<u32>().unwrap());
+        assert!(Cursor::new(1, "part1 24000").key_value("=").is_err());
+    }
+
+    #[test]
+    fn it_takes_text_until_a_delimiter() {
+        let mut cursor = Cursor::new(1, "dir a");
+        let size = cursor.take_until(" ");
+
+        assert_eq!("dir", size.rest());
+        assert_eq!("a", cursor.take_until(" ").rest());
+        assert!(cursor.is_empty());
+    }
+}
@@ -0,0 +1,100 @@
+//! A small pseudo-random number generator, for generating reproducible
+//! puzzle inputs without pulling in a dependency. Not for cryptography.
+
+use std::ops::Range;
+
+/// The SplitMix64 generator: every seed gives its own fixed sequence of numbers.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`, without favoring any of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "Unable to pick a number below 0");
+
+        // Numbers past the last whole multiple of `bound` would favor the low results.
+        let limit = u64::MAX - u64::MAX % bound;
+
+        loop {
+            let number = self.next_u64();
+
+            if number < limit {
+                return number % bound;
+            }
+        }
+    }
+
+    /// A number in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty.
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        assert!(
+            !range.is_empty(),
+            "Unable to pick from the empty range {:?}",
+            range
+        );
+
+        range.start + self.below((range.end - range.start) as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rng;
+
+    #[test]
+    fn it_follows_the_splitmix64_sequence() {
+        let mut rng = Rng::new(0);
+
+        assert_eq!(0xe220a8397b1dcdaf, rng.next_u64());
+        assert_eq!(0x6e789e6aa1b965f4, rng.next_u64());
+    }
+
+    #[test]
+    fn it_repeats_for_the_same_seed() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..10).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+    }
+
+    #[test]
+    fn it_stays_within_bounds() {
+        let mut rng = Rng::new(1);
+        let mut seen = [false; 10];
+
+        for _ in 0..1000 {
+            let number = rng.range(5..15);
+
+            assert!((5..15).contains(&number));
+            seen[number - 5] = true;
+        }
+
+        assert!(seen.iter().all(|seen| *seen));
+        assert_eq!(0, rng.below(1));
+    }
+}
@@ -0,0 +1,345 @@
+use std::time::Duration;
+use std::{env, process};
+
+use crate::answers::{ExpectedAnswers, Verdict};
+use crate::output::{self, Format};
+use crate::{bench, Answer, Error, Input, Part, Result, Solver};
+
+/// The outcome of running a single part of a day's puzzle.
+pub struct PartResult {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Result<Answer>,
+    pub verdict: Verdict,
+    pub elapsed: Duration,
+}
+
+impl PartResult {
+    /// Whether this part errored or produced an unexpected answer.
+    pub fn failed(&self) -> bool {
+        self.answer.is_err() || self.verdict == Verdict::Fail
+    }
+}
+
+/// Options shared by the per-day binaries and `aoc run`.
+#[derive(Debug, Default)]
+pub struct Options {
+    pub part: Option<Part>,
+    pub input: Option<String>,
+    pub bench: bool,
+    pub runs: Option<usize>,
+    pub format: Format,
+}
+
+impl Options {
+    /// Parse `[--part <1|2>] [--bench [--runs <N>]] [--format <text|json|csv>] [input|-]`
+    /// from the given arguments.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    ///   - An unknown flag is supplied
+    ///   - `--part` is missing its value or the value is not `1` or `2`
+    ///   - `--runs` is missing its value or the value is not a number
+    ///   - `--format` is missing its value or the value is not a known format
+    ///   - More than one input file is supplied
+    pub fn parse<I: Iterator<Item = String>>(args: I) -> Result<Self> {
+        Self::parse_with(args, &mut ())
+    }
+
+    /// Parse the shared options, handing any other flag to `extension`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error in the same cases as [`Options::parse`],
+    /// or if `extension` rejects one of its flags or how they are combined.
+    pub fn parse_with<I: Iterator<Item = String>>(
+        mut args: I,
+        extension: &mut dyn Extension,
+    ) -> Result<Self> {
+        let mut options = Options::default();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" | "-p" => {
+                    let value = args.next().unwrap_or_default();
+                    let part = Part::from_number(&value).ok_or_else(|| {
+                        Error::invalid(format!("Invalid part `{}`, expected 1 or 2", value))
+                    })?;
+
+                    options.part = Some(part);
+                }
+                "--bench" => options.bench = true,
+                "--runs" => {
+                    let value = args.next().unwrap_or_default();
+                    let runs = value.parse().map_err(|_| {
+                        Error::invalid(format!("Invalid run count `{}`, expected a number", value))
+                    })?;
+
+                    options.runs = Some(runs);
+                }
+                "--format" => {
+                    let value = args.next().unwrap_or_default();
+                    let format = Format::from_name(&value).ok_or_else(|| {
+                        Error::invalid(format!(
+                            "Invalid format `{}`, expected text, json or csv",
+                            value
+                        ))
+                    })?;
+
+                    options.format = format;
+                }
+                flag if flag.starts_with("--") => {
+                    if !extension.flag(flag, &mut args)? {
+                        return Err(Error::invalid(format!("Unknown option `{}`", flag)));
+                    }
+                }
+                _ if options.input.is_some() => {
+                    return Err(Error::invalid(format!("Unexpected argument `{}`", arg)));
+                }
+                _ => options.input = Some(arg),
+            }
+        }
+
+        extension.check(&options)?;
+
+        Ok(options)
+    }
+
+    /// The parts selected by these options.
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+
+    /// How many times each day is run when benchmarking.
+    pub fn runs(&self) -> usize {
+        self.runs.unwrap_or(bench::DEFAULT_RUNS)
+    }
+}
+
+/// Extra flags understood by a single day's binary on top of the shared
+/// [`Options`], and what to do with the input once the answers are printed.
+pub trait Extension {
+    /// Handle a flag the shared options do not know, taking any value it
+    /// needs from `args`. Returns whether the flag was understood.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the flag's value is invalid.
+    fn flag(&mut self, flag: &str, args: &mut dyn Iterator<Item = String>) -> Result<bool>;
+
+    /// Check the extension's flags against the shared options once every
+    /// argument has been parsed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the flags cannot be used together.
+    fn check(&self, _options: &Options) -> Result<()> {
+        Ok(())
+    }
+
+    /// The solver to use instead of the day's own, when the extension's
+    /// flags change how the puzzle is solved.
+    fn solver(&self) -> Option<&dyn Solver> {
+        None
+    }
+
+    /// Run after the answers have been printed, with the same input and
+    /// the format they were printed in.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the extension fails.
+    fn run(&self, input: &str, format: Format) -> Result<()>;
+}
+
+/// No extra flags.
+impl Extension for () {
+    fn flag(&mut self, _flag: &str, _args: &mut dyn Iterator<Item = String>) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn run(&self, _input: &str, _format: Format) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Solve the requested parts of a day's puzzle, timing each one and
+/// checking it against the expected answers.
+///
+/// # Errors
+///
+/// This function will return an error if the input cannot be parsed.
+pub fn run_parts(
+    solver: &dyn Solver,
+    input: &str,
+    parts: &[Part],
+    expected: &ExpectedAnswers,
+) -> Result<Vec<PartResult>> {
+    let solved = solver.solve_parts(input, parts)?;
+
+    let results = solved
+        .into_iter()
+        .map(|(part, answer, elapsed)| {
+            let verdict = match &answer {
+                Ok(answer) => expected.verify(solver.day(), part, answer),
+                Err(_) => Verdict::Unknown,
+            };
+
+            PartResult {
+                day: solver.day(),
+                part,
+                answer,
+                verdict,
+                elapsed,
+            }
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// Run a day against the given input, checking the answers against the
+/// `answers.toml` next to the input.
+///
+/// # Errors
+///
+/// This function will return an error if:
+///   - We are unable to read the input or the answers file
+///   - The input cannot be parsed
+pub fn run_day(solver: &dyn Solver, input: &Input, parts: &[Part]) -> Result<Vec<PartResult>> {
+    let expected = input.expected_answers()?;
+    let input = input.read()?;
+
+    run_parts(solver, &input, parts, &expected)
+}
+
+/// Benchmark a day against the given input.
+///
+/// # Errors
+///
+/// This function will return an error if we are unable to read the input,
+/// or parsing or solving the puzzle fails.
+pub fn bench_day(
+    solver: &dyn Solver,
+    input: &Input,
+    parts: &[Part],
+    runs: usize,
+) -> Result<bench::Report> {
+    let input = input.read()?;
+
+    bench::run(solver, &input, parts, runs)
+}
+
+/// Print the answer for each part, one per line.
+pub fn print_answers(results: &[PartResult]) {
+    for result in results {
+        match &result.answer {
+            Ok(answer) => println!("Part {}: {} [{}]", result.part, answer, result.verdict),
+            Err(e) => println!("Part {}: error: {}", result.part, e),
+        }
+    }
+}
+
+/// Print a table with one row per day and a column per part.
+pub fn print_summary(results: &[PartResult]) {
+    let cell = |day: u8, part: Part| -> String {
+        match results.iter().find(|r| r.day == day && r.part == part) {
+            Some(PartResult {
+                answer: Ok(a),
+                verdict,
+                ..
+            }) => format!("{} [{}]", a, verdict),
+            Some(PartResult { answer: Err(_), .. }) => "error".to_string(),
+            None => "-".to_string(),
+        }
+    };
+
+    let mut days: Vec<u8> = results.iter().map(|r| r.day).collect();
+    days.dedup();
+
+    let rows: Vec<(u8, String, String, Duration)> = days
+        .into_iter()
+        .map(|day| {
+            let elapsed = results
+                .iter()
+                .filter(|r| r.day == day)
+                .map(|r| r.elapsed)
+                .sum();
+
+            (day, cell(day, Part::One), cell(day, Part::Two), elapsed)
+        })
+        .collect();
+
+    let width_1 = rows.iter().map(|r| r.1.len()).max().unwrap_or(0).max(6);
+    let width_2 = rows.iter().map(|r| r.2.len()).max().unwrap_or(0).max(6);
+
+    println!(
+        "Day | {:<w1$} | {:<w2$} | Time",
+        "Part 1",
+        "Part 2",
+        w1 = width_1,
+        w2 = width_2
+    );
+    println!(
+        "----+-{}-+-{}-+---------",
+        "-".repeat(width_1),
+        "-".repeat(width_2)
+    );
+
+    for (day, part_1, part_2, elapsed) in rows {
+        println!(
+            "{:>3} | {:<w1$} | {:<w2$} | {:.2?}",
+            day,
+            part_1,
+            part_2,
+            elapsed,
+            w1 = width_1,
+            w2 = width_2
+        );
+    }
+}
+
+/// Entry point for a single day's binary: `day_N [--part <1|2>] [--bench] [--format <F>] [input]`.
+pub fn main(solver: &dyn Solver) {
+    main_with(solver, &mut ());
+}
+
+/// Entry point for a single day's binary that understands extra flags.
+pub fn main_with(solver: &dyn Solver, extension: &mut dyn Extension) {
+    match run_single(solver, extension) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Returns whether every part produced its expected answer.
+fn run_single(solver: &dyn Solver, extension: &mut dyn Extension) -> Result<bool> {
+    let options = Options::parse_with(env::args().skip(1), extension)?;
+    let solver = extension.solver().unwrap_or(solver);
+    let input = Input::resolve(solver.day(), options.input.as_deref());
+
+    if options.bench {
+        let report = bench_day(solver, &input, &options.parts(), options.runs())?;
+        output::print_reports(&[report], options.format);
+
+        return Ok(true);
+    }
+
+    // Read the input once, so stdin is still available to the extension.
+    let expected = input.expected_answers()?;
+    let input = input.read()?;
+
+    let results = run_parts(solver, &input, &options.parts(), &expected)?;
+    output::print_results(&results, options.format);
+    extension.run(&input, options.format)?;
+
+    Ok(!results.iter().any(PartResult::failed))
+}
@@ -0,0 +1,202 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::bench::Phase;
+use crate::Result;
+
+/// One of the two halves of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// Parse a part number as given on the command line (`1` or `2`).
+    pub fn from_number(number: &str) -> Option<Self> {
+        match number {
+            "1" => Some(Part::One),
+            "2" => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// The answer to a single part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// Wide enough to hold every `u64`, `usize` and `i64` answer unchanged.
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+/// Only for types whose every value fits in an `i128`, so no answer wraps.
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(number: $t) -> Self {
+                    Answer::Number(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(u8, u16, u32, u64, usize, i32, i64);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// A day's puzzle, split into parsing the input and solving each part.
+pub trait Solution {
+    /// The day of the advent calendar this solution belongs to.
+    const DAY: u8;
+
+    /// The puzzle input once parsed, shared by both parts.
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer>;
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer>;
+}
+
+/// The answer to a part along with how long it took to solve.
+pub type SolvedPart = (Part, Result<Answer>, Duration);
+
+/// A day's puzzle, solvable through the shared runner.
+///
+/// This is implemented for every [`Solution`] so that days can be stored
+/// side by side as trait objects.
+pub trait Solver {
+    /// The day of the advent calendar this solver belongs to.
+    fn day(&self) -> u8;
+
+    /// Solve a single part of the puzzle for the given input.
+    fn solve(&self, input: &str, part: Part) -> Result<Answer>;
+
+    /// Parse the input once and solve each of the given parts, timing each part.
+    /// Only a failure to parse fails the whole call.
+    fn solve_parts(&self, input: &str, parts: &[Part]) -> Result<Vec<SolvedPart>>;
+
+    /// Parse the input once and solve each of the given parts, timing every phase.
+    fn time_phases(&self, input: &str, parts: &[Part]) -> Result<Vec<(Phase, Duration)>>;
+}
+
+impl<S: Solution> Solver for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn solve(&self, input: &str, part: Part) -> Result<Answer> {
+        solve_part::<S>(&S::parse(input)?, part)
+    }
+
+    fn solve_parts(&self, input: &str, parts: &[Part]) -> Result<Vec<SolvedPart>> {
+        Ok(solve_each(&S::parse(input)?, parts, solve_part::<S>))
+    }
+
+    fn time_phases(&self, input: &str, parts: &[Part]) -> Result<Vec<(Phase, Duration)>> {
+        time_each(input, parts, S::parse, solve_part::<S>)
+    }
+}
+
+fn solve_part<S: Solution>(parsed: &S::Parsed, part: Part) -> Result<Answer> {
+    match part {
+        Part::One => S::part1(parsed),
+        Part::Two => S::part2(parsed),
+    }
+}
+
+/// Solve each of the given parts of an input parsed once, timing each part.
+/// For [`Solver`]s that parse their input with more than a [`Solution`] knows.
+pub fn solve_each<P>(
+    parsed: &P,
+    parts: &[Part],
+    solve: impl Fn(&P, Part) -> Result<Answer>,
+) -> Vec<SolvedPart> {
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = solve(parsed, part);
+
+            (part, answer, start.elapsed())
+        })
+        .collect()
+}
+
+/// Parse the input once and solve each of the given parts, timing every
+/// phase. Fails as soon as parsing or any part fails.
+pub fn time_each<P>(
+    input: &str,
+    parts: &[Part],
+    parse: impl FnOnce(&str) -> Result<P>,
+    solve: impl Fn(&P, Part) -> Result<Answer>,
+) -> Result<Vec<(Phase, Duration)>> {
+    let start = Instant::now();
+    let parsed = parse(input)?;
+    let mut timings = vec![(Phase::Parse, start.elapsed())];
+
+    for &part in parts {
+        let start = Instant::now();
+        solve(&parsed, part)?;
+
+        timings.push((Phase::Part(part), start.elapsed()));
+    }
+
+    Ok(timings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn it_converts_numbers_and_text_into_answers() {
+        assert_eq!(Answer::Number(42), Answer::from(42_usize));
+        assert_eq!(Answer::Number(42), Answer::from(42_u32));
+        assert_eq!(Answer::Number(-42), Answer::from(-42_i64));
+        assert_eq!("18446744073709551615", Answer::from(u64::MAX).to_string());
+        assert_eq!(usize::MAX.to_string(), Answer::from(usize::MAX).to_string());
+        assert_eq!(Answer::Text("CMZ".to_string()), Answer::from("CMZ"));
+        assert_ne!(Answer::from("42"), Answer::from(42));
+    }
+
+    #[test]
+    fn it_displays_answers_without_decoration() {
+        assert_eq!("24000", Answer::from(24000_u32).to_string());
+        assert_eq!("MCD", Answer::from("MCD".to_string()).to_string());
+    }
+}